@@ -1,9 +1,50 @@
+use std::time::Instant;
+
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Direction, Point, Rect, TilingDirection};
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct ActiveDrag {
   pub operation: Option<ActiveDragOperation>,
   pub is_from_tiling: bool,
+
+  /// Cursor position when the drag was started.
+  pub start_position: Option<Point>,
+
+  /// Position in the tree of the window when the drag was started. Only
+  /// set for tiling windows.
+  pub tiling_origin: Option<DragTilingOrigin>,
+
+  /// ID of the monitor that the window was on when the drag was started.
+  pub start_monitor_id: Option<Uuid>,
+
+  /// Screen edge that the cursor is currently held at. Used for switching
+  /// workspaces by dragging to the edge of the screen.
+  #[serde(skip)]
+  pub edge_dwell: Option<DragEdgeDwell>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DragEdgeDwell {
+  /// Screen edge that the cursor is held at.
+  pub direction: Direction,
+
+  /// When the cursor reached the edge.
+  pub since: Instant,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DragTilingOrigin {
+  /// ID of the window's parent container.
+  pub parent_id: Uuid,
+
+  /// Index of the window within its parent.
+  pub index: usize,
+
+  /// Tiling rect of the window.
+  pub rect: Rect,
 }
 
 #[derive(Debug, Copy, Clone, Deserialize, PartialEq, Serialize)]
@@ -11,3 +52,113 @@ pub enum ActiveDragOperation {
   Moving,
   Resizing,
 }
+
+/// Outcome of a drag once it's ended.
+#[derive(Debug, Copy, Clone, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DragResult {
+  /// Window was placed in (or resized within) the tiling layout.
+  Tiled,
+  /// Window was moved or resized as a floating window.
+  Floated,
+  /// Window ended up on a different monitor.
+  MovedMonitor,
+  /// Window was restored to its position from before the drag.
+  SnappedBack,
+}
+
+/// Represents where the window was dropped over another.
+#[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropPosition {
+  Top,
+  Bottom,
+  Left,
+  Right,
+  Center,
+}
+
+impl DropPosition {
+  /// Gets the edge drop position in the given direction.
+  #[must_use]
+  pub fn from_direction(direction: &Direction) -> Self {
+    match direction {
+      Direction::Up => DropPosition::Top,
+      Direction::Down => DropPosition::Bottom,
+      Direction::Left => DropPosition::Left,
+      Direction::Right => DropPosition::Right,
+    }
+  }
+}
+
+/// Where a window would be placed if dropped at a given point.
+#[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DropPlan {
+  /// ID of the direction container that the window is dropped into.
+  pub target_parent_id: Uuid,
+
+  /// ID of the container that the window is placed relative to.
+  pub target_id: Uuid,
+
+  /// Side of the target that the window is dropped on.
+  pub position: DropPosition,
+
+  /// Index that the window is inserted at. This is within the created
+  /// split container if the target is split, and otherwise within the
+  /// target parent.
+  pub insert_index: usize,
+
+  /// Tiling direction of the split container that's created around the
+  /// target, if the target is split.
+  pub split_direction: Option<TilingDirection>,
+
+  /// Whether the window swaps positions with the target instead.
+  pub is_swap: bool,
+}
+
+/// Gets the tiling direction that a drop at the given position implies.
+///
+/// Dropping on the left or right edge implies a horizontal layout, and on
+/// the top or bottom edge a vertical layout. A center drop keeps the
+/// parent's direction. If the result differs from the parent's direction,
+/// the target needs to be split.
+///
+/// Example:
+/// ```
+/// # use wm_common::{desired_split_direction, DropPosition, TilingDirection};
+/// use TilingDirection::{Horizontal, Vertical};
+///
+/// let cases = [
+///   (DropPosition::Left, Horizontal, Horizontal),
+///   (DropPosition::Left, Vertical, Horizontal),
+///   (DropPosition::Right, Horizontal, Horizontal),
+///   (DropPosition::Right, Vertical, Horizontal),
+///   (DropPosition::Top, Horizontal, Vertical),
+///   (DropPosition::Top, Vertical, Vertical),
+///   (DropPosition::Bottom, Horizontal, Vertical),
+///   (DropPosition::Bottom, Vertical, Vertical),
+///   (DropPosition::Center, Horizontal, Horizontal),
+///   (DropPosition::Center, Vertical, Vertical),
+/// ];
+///
+/// for (position, parent_direction, expected) in cases {
+///   assert_eq!(
+///     desired_split_direction(&position, &parent_direction),
+///     expected,
+///   );
+/// }
+/// ```
+#[must_use]
+pub fn desired_split_direction(
+  drop_position: &DropPosition,
+  parent_direction: &TilingDirection,
+) -> TilingDirection {
+  match drop_position {
+    DropPosition::Left | DropPosition::Right => {
+      TilingDirection::Horizontal
+    }
+    DropPosition::Top | DropPosition::Bottom => TilingDirection::Vertical,
+    DropPosition::Center => parent_direction.clone(),
+  }
+}
@@ -131,6 +131,14 @@ pub enum QueryCommand {
   Workspaces,
   /// Outputs whether the window manager is paused.
   Paused,
+  /// Outputs the top-most window at the given point in screen space.
+  WindowAtPoint {
+    #[clap(long, allow_hyphen_values = true)]
+    x: i32,
+
+    #[clap(long, allow_hyphen_values = true)]
+    y: i32,
+  },
 }
 
 #[derive(Clone, Debug, PartialEq, ValueEnum)]
@@ -139,6 +147,8 @@ pub enum SubscribableEvent {
   All,
   ApplicationExiting,
   BindingModesChanged,
+  DragEnded,
+  DragStarted,
   FocusChanged,
   FocusedContainerMoved,
   MonitorAdded,
@@ -147,6 +157,7 @@ pub enum SubscribableEvent {
   TilingDirectionChanged,
   UserConfigChanged,
   WindowManaged,
+  WindowMovedOrResizedEnded,
   WindowUnmanaged,
   WorkspaceActivated,
   WorkspaceDeactivated,
@@ -157,15 +168,54 @@ pub enum SubscribableEvent {
 #[derive(Clone, Debug, Parser, PartialEq, Serialize)]
 pub enum InvokeCommand {
   AdjustBorders(InvokeAdjustBordersCommand),
+  Balance {
+    /// Balance the subject's whole workspace instead of only its parent
+    /// container.
+    #[clap(long, default_value_t = false)]
+    workspace: bool,
+  },
   Close,
+  EqualizeAxis {
+    #[clap(required = true)]
+    tiling_direction: TilingDirection,
+  },
+  FloatAllOfApp {
+    /// Process name of the application. Defaults to the application of
+    /// the subject window.
+    #[clap(long)]
+    process: Option<String>,
+  },
   Focus(InvokeFocusCommand),
+  FocusMonitorByPosition {
+    #[clap(long, allow_hyphen_values = true)]
+    x: i32,
+
+    #[clap(long, allow_hyphen_values = true)]
+    y: i32,
+  },
+  Grab {
+    #[clap(long)]
+    direction: Option<Direction>,
+
+    #[clap(long, default_value_t = false)]
+    confirm: bool,
+
+    #[clap(long, default_value_t = false)]
+    cancel: bool,
+  },
   Ignore,
   Move(InvokeMoveCommand),
   MoveWorkspace {
     #[clap(long)]
     direction: Direction,
   },
+  PeekFocused {
+    #[clap(long, default_value_t = 1.5)]
+    scale: f32,
+  },
   Position(InvokePositionCommand),
+  Redo,
+  RescueAllFloating,
   Resize(InvokeResizeCommand),
   SetFloating {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
@@ -194,6 +244,10 @@ pub enum InvokeCommand {
     maximized: Option<bool>,
   },
   SetMinimized,
+  SetSticky {
+    #[clap(required = true)]
+    sticky: bool,
+  },
   SetTiling,
   SetTitleBarVisibility {
     #[clap(required = true, value_enum)]
@@ -226,6 +280,8 @@ pub enum InvokeCommand {
   ToggleMinimized,
   ToggleTiling,
   ToggleTilingDirection,
+  TransposeLayout,
+  Undo,
   SetTilingDirection {
     #[clap(required = true)]
     tiling_direction: TilingDirection,
@@ -339,6 +395,15 @@ pub struct InvokeFocusCommand {
 
   #[clap(long)]
   pub recent_workspace: bool,
+
+  #[clap(long)]
+  pub next_floating: bool,
+
+  #[clap(long)]
+  pub prev_floating: bool,
+
+  #[clap(long)]
+  pub under_cursor: bool,
 }
 
 #[derive(Args, Clone, Debug, PartialEq, Serialize)]
@@ -353,6 +418,10 @@ pub struct InvokeMoveCommand {
   #[clap(long)]
   pub workspace_in_direction: Option<Direction>,
 
+  /// Move window to monitor in specified direction.
+  #[clap(long)]
+  pub monitor_in_direction: Option<Direction>,
+
   /// Name of workspace to move the window.
   #[clap(long)]
   pub workspace: Option<String>,
@@ -35,6 +35,7 @@ pub enum ClientResponseData {
   Windows(WindowsData),
   Workspaces(WorkspacesData),
   Paused(bool),
+  WindowAtPoint(WindowAtPointData),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -86,6 +87,12 @@ pub struct WindowsData {
   pub windows: Vec<ContainerDto>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowAtPointData {
+  pub window: Option<ContainerDto>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkspacesData {
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-  app_command::InvokeCommand, Color, LengthValue, OpacityValue, RectDelta,
+  app_command::InvokeCommand, Color, DropPosition, LengthValue,
+  OpacityValue, RectDelta,
 };
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -67,13 +68,21 @@ impl Default for GapsConfig {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct GeneralConfig {
   /// Config for automatically moving the cursor.
   pub cursor_jump: CursorJumpConfig,
 
+  /// Config for changing focus between windows.
+  pub focus: FocusConfig,
+
   /// Whether to automatically focus windows underneath the cursor.
   pub focus_follows_cursor: bool,
 
+  /// Whether scrolling the mouse wheel over an empty area of a monitor
+  /// should switch the monitor's displayed workspace.
+  pub switch_workspace_on_scroll: bool,
+
   /// Whether to switch back and forth between the previously focused
   /// workspace when focusing the current workspace.
   pub toggle_workspace_on_refocus: bool,
@@ -93,19 +102,26 @@ pub struct GeneralConfig {
 
   /// Affects which windows get shown in the native Windows taskbar.
   pub show_all_in_taskbar: bool,
+
+  /// Maximum number of layout changes that can be undone via the `undo`
+  /// command. A value of 0 disables this.
+  pub undo_limit: usize,
 }
 
 impl Default for GeneralConfig {
   fn default() -> Self {
     GeneralConfig {
       cursor_jump: CursorJumpConfig::default(),
+      focus: FocusConfig::default(),
       focus_follows_cursor: false,
+      switch_workspace_on_scroll: false,
       toggle_workspace_on_refocus: true,
       startup_commands: vec![],
       shutdown_commands: vec![],
       config_reload_commands: vec![],
       hide_method: HideMethod::Cloak,
       show_all_in_taskbar: false,
+      undo_limit: 20,
     }
   }
 }
@@ -118,6 +134,9 @@ pub struct CursorJumpConfig {
 
   /// Trigger for cursor jump.
   pub trigger: CursorJumpTrigger,
+
+  /// Where on the jump target the cursor is placed.
+  pub anchor: CursorJumpAnchor,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -128,6 +147,48 @@ pub enum CursorJumpTrigger {
   WindowFocus,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CursorJumpAnchor {
+  #[default]
+  Center,
+  TopLeft,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct FocusConfig {
+  /// Whether focusing in a direction wraps around to the opposite edge of
+  /// the workspace, instead of moving to the next monitor.
+  pub wrap_workspace: bool,
+
+  /// How to pick the window to focus when focus moves to another monitor.
+  pub cross_monitor: CrossMonitorFocus,
+
+  /// Whether focusing in a direction skips over minimized windows.
+  pub skip_minimized: bool,
+}
+
+impl Default for FocusConfig {
+  fn default() -> Self {
+    FocusConfig {
+      wrap_workspace: false,
+      cross_monitor: CrossMonitorFocus::default(),
+      skip_minimized: true,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossMonitorFocus {
+  /// Focus the window nearest to the edge that's being crossed.
+  #[default]
+  Spatial,
+  /// Focus the most recently focused window on the monitor.
+  Recent,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum HideMethod {
@@ -152,10 +213,46 @@ pub struct WindowBehaviorConfig {
   /// New windows are created in this state whenever possible.
   pub initial_state: InitialWindowState,
 
+  /// Where new tiling windows are inserted in the tree.
+  pub insertion_mode: InsertionMode,
+
   /// Sets the default options for when a new window is created. This also
   /// changes the defaults for when the state change commands, like
   /// `set_floating`, are used without any flags.
   pub state_defaults: WindowStateDefaultsConfig,
+
+  /// Config for moving and resizing windows via their drag handles.
+  pub drag: DragConfig,
+
+  /// Config for dropping dragged tiling windows back into the tree.
+  pub drop: DropConfig,
+
+  /// Config for moving floating windows.
+  pub floating: FloatingConfig,
+
+  /// Bounds for the size of windows when they're resized.
+  pub size_limits: SizeLimitsConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct SizeLimitsConfig {
+  /// Minimum width (in pixels) that a window can be resized to. For
+  /// tiling windows, this is also kept free for each sibling. A value of
+  /// 0 disables the limit.
+  pub min_width: i32,
+
+  /// Minimum height (in pixels) that a window can be resized to. A value
+  /// of 0 disables the limit.
+  pub min_height: i32,
+
+  /// Maximum width (in pixels) that a window can be resized to. A value
+  /// of 0 disables the limit.
+  pub max_width: i32,
+
+  /// Maximum height (in pixels) that a window can be resized to. A value
+  /// of 0 disables the limit.
+  pub max_height: i32,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -166,6 +263,156 @@ pub enum InitialWindowState {
   Floating,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InsertionMode {
+  /// Insert new windows after the focused tiling window.
+  #[default]
+  Default,
+  /// Split the focused tiling window perpendicular to its parent's tiling
+  /// direction, such that new windows spiral inwards.
+  Spiral,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct DragConfig {
+  /// Whether to restore a window to its pre-drag position when the drag
+  /// is canceled (e.g. by pressing escape mid-drag).
+  pub restore_on_cancel: bool,
+
+  /// Minimum distance (in pixels) the cursor has to move from where a
+  /// tiling window was picked up for it to be dropped elsewhere. Shorter
+  /// drags snap the window back to its original position.
+  pub tiling_threshold_px: i32,
+
+  /// Distance (in pixels) from the left or right edge of the screen
+  /// within which a dragged window is moved to the previous or next
+  /// workspace. A value of 0 disables this.
+  pub edge_dwell_px: i32,
+
+  /// How long (in milliseconds) the cursor has to be held at the edge of
+  /// the screen before the dragged window is moved.
+  pub edge_dwell_ms: u64,
+
+  /// Time (in milliseconds) within which repeated drag end events for the
+  /// same window are ignored. Some systems fire several of these for a
+  /// single drag.
+  pub end_debounce_ms: u64,
+
+  /// Whether to move the cursor to the center of a dropped window when
+  /// the drop moves it to another monitor.
+  pub recenter_cursor_on_monitor_change: bool,
+}
+
+impl Default for DragConfig {
+  fn default() -> Self {
+    DragConfig {
+      restore_on_cancel: false,
+      tiling_threshold_px: 20,
+      edge_dwell_px: 0,
+      edge_dwell_ms: 500,
+      end_debounce_ms: 50,
+      recenter_cursor_on_monitor_change: false,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct FloatingConfig {
+  /// Distance (in pixels) within which a dragged floating window snaps
+  /// to the edges of its monitor and of other floating windows. A value
+  /// of 0 disables snapping.
+  pub snap_distance_px: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct DropConfig {
+  /// Size of the center drop zone as a fraction of the target's width
+  /// and height. Dropping within this zone places the window beside the
+  /// target instead of splitting it. A value of 0 disables the zone.
+  pub center_fraction: f32,
+
+  /// Whether dropping a window onto the center zone of another window
+  /// swaps the positions of the two windows.
+  pub swap_on_center: bool,
+
+  /// Whether a dropped window should keep its floating size as closely as
+  /// possible, rather than taking the size of its new tiling slot.
+  pub preserve_floating_size: bool,
+
+  /// Tiling size of a dropped window as a fraction of the split container
+  /// that's created by the drop. Must be between 0 and 1 (exclusive).
+  pub new_split_ratio: f32,
+
+  /// Which window a dropped window is placed relative to.
+  pub anchor: DropAnchor,
+
+  /// What to do when a window is dropped onto a workspace whose focused
+  /// window is fullscreen.
+  pub on_fullscreen: DropOnFullscreen,
+
+  /// Rules that override where matching windows are dropped. Only the
+  /// first matching rule is applied.
+  pub rules: Vec<DropRuleConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all(serialize = "camelCase"))]
+pub struct DropRuleConfig {
+  #[serde(rename = "match")]
+  pub match_window: Vec<WindowMatchConfig>,
+
+  /// Name of the workspace to drop the window into, instead of the
+  /// workspace under the cursor.
+  #[serde(default)]
+  pub workspace: Option<String>,
+
+  /// Side of the target window to drop the window on, instead of the
+  /// side nearest to the cursor.
+  #[serde(default)]
+  pub position: Option<DropPosition>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropAnchor {
+  /// Place relative to the window nearest to the cursor.
+  #[default]
+  Cursor,
+  /// Place relative to the focused window in the target container,
+  /// falling back to the window nearest to the cursor.
+  Focused,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropOnFullscreen {
+  /// Exit fullscreen on the fullscreen window before placing the dropped
+  /// window.
+  #[default]
+  Unfullscreen,
+  /// Reject the drop and return the window to where it was picked up
+  /// from.
+  Ignore,
+}
+
+impl Default for DropConfig {
+  fn default() -> Self {
+    DropConfig {
+      center_fraction: 0.25,
+      swap_on_center: false,
+      preserve_floating_size: false,
+      new_split_ratio: 0.5,
+      anchor: DropAnchor::Cursor,
+      on_fullscreen: DropOnFullscreen::Unfullscreen,
+      rules: Vec::new(),
+    }
+  }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
 pub struct WindowStateDefaultsConfig {
@@ -301,6 +548,7 @@ pub struct TransparencyEffectConfig {
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all(serialize = "camelCase"))]
 pub struct WindowRuleConfig {
+  #[serde(default)]
   pub commands: Vec<InvokeCommand>,
 
   #[serde(rename = "match")]
@@ -311,6 +559,40 @@ pub struct WindowRuleConfig {
 
   #[serde(default = "default_bool::<true>")]
   pub run_once: bool,
+
+  /// Size and position to apply when a matching window first becomes
+  /// floating.
+  #[serde(default)]
+  pub floating_placement: Option<FloatingPlacementConfig>,
+
+  /// Whether to preserve the window's native aspect ratio when it's
+  /// tiling. The window is centered within its tile instead of filling
+  /// it.
+  #[serde(default)]
+  pub preserve_aspect_ratio: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct FloatingPlacementConfig {
+  /// Whether to center the window within its workspace. Takes precedence
+  /// over `x_pos` and `y_pos`.
+  pub centered: bool,
+
+  pub x_pos: Option<i32>,
+  pub y_pos: Option<i32>,
+
+  /// Width of the window. Percentages are relative to the window's
+  /// monitor.
+  pub width: Option<LengthValue>,
+
+  /// Height of the window. Percentages are relative to the window's
+  /// monitor.
+  pub height: Option<LengthValue>,
+
+  /// Whether to apply the placement every time the window becomes
+  /// floating, rather than only the first time.
+  pub reapply: bool,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -1,6 +1,129 @@
+use std::ops::{Add, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Rect;
+
 /// Represents an x-y coordinate.
-#[derive(Debug, Clone)]
+///
+/// Serializes with stable `x` and `y` field names.
+///
+/// Example:
+/// ```
+/// # use wm_common::Point;
+/// let point = Point { x: -1920, y: 1080 };
+/// let json = serde_json::to_string(&point).unwrap();
+/// assert_eq!(json, r#"{"x":-1920,"y":1080}"#);
+/// assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), point);
+/// ```
+#[derive(Debug, Clone, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Point {
   pub x: i32,
   pub y: i32,
 }
+
+impl Point {
+  /// Gets the straight-line (Euclidean) distance to another point.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Point;
+  /// let point = Point { x: -3, y: -4 };
+  /// let distance = point.distance_to(&Point { x: 0, y: 0 });
+  /// assert!((distance - 5.0).abs() < f64::EPSILON);
+  /// ```
+  #[must_use]
+  pub fn distance_to(&self, other: &Point) -> f64 {
+    let delta_x = f64::from(other.x) - f64::from(self.x);
+    let delta_y = f64::from(other.y) - f64::from(self.y);
+
+    delta_x.hypot(delta_y)
+  }
+
+  /// Gets the distance to another point when only moving along the x and
+  /// y axes.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Point;
+  /// let point = Point { x: -1920, y: 10 };
+  /// let distance = point.manhattan_distance_to(&Point { x: 10, y: -20 });
+  /// assert_eq!(distance, 1960);
+  /// ```
+  #[must_use]
+  pub fn manhattan_distance_to(&self, other: &Point) -> i32 {
+    (other.x - self.x).abs() + (other.y - self.y).abs()
+  }
+
+  /// Gets the component-wise minimum of two points.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Point;
+  /// let point = Point { x: -5, y: 10 }.min(&Point { x: 0, y: -10 });
+  /// assert_eq!((point.x, point.y), (-5, -10));
+  /// ```
+  #[must_use]
+  pub fn min(&self, other: &Point) -> Point {
+    Point {
+      x: self.x.min(other.x),
+      y: self.y.min(other.y),
+    }
+  }
+
+  /// Gets the component-wise maximum of two points.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Point;
+  /// let point = Point { x: -5, y: 10 }.max(&Point { x: 0, y: -10 });
+  /// assert_eq!((point.x, point.y), (0, 10));
+  /// ```
+  #[must_use]
+  pub fn max(&self, other: &Point) -> Point {
+    Point {
+      x: self.x.max(other.x),
+      y: self.y.max(other.y),
+    }
+  }
+
+  /// Clamps the point to be within the bounds of the given rect
+  /// (inclusive of its edges).
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::{Point, Rect};
+  /// let rect = Rect::from_ltrb(-1920, 0, 0, 1080);
+  /// let point = Point { x: 100, y: -50 }.clamp_to_rect(&rect);
+  /// assert_eq!((point.x, point.y), (0, 0));
+  /// ```
+  #[must_use]
+  pub fn clamp_to_rect(&self, rect: &Rect) -> Point {
+    Point {
+      x: self.x.clamp(rect.left, rect.right.max(rect.left)),
+      y: self.y.clamp(rect.top, rect.bottom.max(rect.top)),
+    }
+  }
+}
+
+impl Add for Point {
+  type Output = Point;
+
+  fn add(self, other: Point) -> Point {
+    Point {
+      x: self.x + other.x,
+      y: self.y + other.y,
+    }
+  }
+}
+
+impl Sub for Point {
+  type Output = Point;
+
+  fn sub(self, other: Point) -> Point {
+    Point {
+      x: self.x - other.x,
+      y: self.y - other.y,
+    }
+  }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{Direction, LengthValue, Point, RectDelta};
 
-#[derive(Debug, Deserialize, Clone, Serialize, Eq, PartialEq)]
+#[derive(Debug, Deserialize, Clone, Serialize, Eq, Hash, PartialEq)]
 pub struct Rect {
   /// X-coordinate of the left edge of the rectangle.
   pub left: i32,
@@ -75,6 +75,68 @@ impl Rect {
     )
   }
 
+  /// Scales the rectangle by the given factor while keeping it centered
+  /// on its original center point.
+  #[must_use]
+  #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+  pub fn scaled(&self, factor: f32) -> Self {
+    let width = (self.width() as f32 * factor).round() as i32;
+    let height = (self.height() as f32 * factor).round() as i32;
+
+    Self::from_xy(0, 0, width, height).translate_to_center(self)
+  }
+
+  /// Gets the largest rectangle with the given aspect ratio (width divided
+  /// by height) that fits within this rectangle, centered within it.
+  ///
+  /// Returns the rectangle unchanged if the ratio is not positive.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use wm_common::Rect;
+  /// let tile = Rect::from_xy(0, 0, 1000, 1000);
+  /// let fitted = tile.fit_aspect_ratio(16.0 / 9.0);
+  /// assert_eq!(fitted, Rect::from_xy(0, 219, 1000, 563));
+  ///
+  /// let tall = Rect::from_xy(100, 0, 400, 300);
+  /// let fitted = tall.fit_aspect_ratio(1.0);
+  /// assert_eq!(fitted, Rect::from_xy(150, 0, 300, 300));
+  ///
+  /// // The fitted rectangle always stays within the tile bounds.
+  /// let tiles = [
+  ///   Rect::from_xy(-1921, 7, 1277, 1003),
+  ///   Rect::from_xy(3, -5, 801, 599),
+  ///   Rect::from_xy(0, 0, 1, 1),
+  /// ];
+  ///
+  /// for tile in tiles {
+  ///   for ratio in [0.3, 0.75, 1.0, 4.0 / 3.0, 16.0 / 9.0, 21.0 / 9.0] {
+  ///     let fitted = tile.fit_aspect_ratio(ratio);
+  ///     assert!(fitted.left >= tile.left && fitted.right <= tile.right);
+  ///     assert!(fitted.top >= tile.top && fitted.bottom <= tile.bottom);
+  ///   }
+  /// }
+  /// ```
+  #[must_use]
+  #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+  pub fn fit_aspect_ratio(&self, ratio: f32) -> Self {
+    if ratio <= 0.0 || self.height() <= 0 {
+      return self.clone();
+    }
+
+    let width = self.width() as f32;
+    let height = self.height() as f32;
+
+    let (width, height) = if width / height > ratio {
+      ((height * ratio).round() as i32, self.height())
+    } else {
+      (self.width(), (width / ratio).round() as i32)
+    };
+
+    Self::from_xy(0, 0, width, height).translate_to_center(self)
+  }
+
   #[must_use]
   pub fn translate_in_direction(
     &self,
@@ -119,11 +181,36 @@ impl Rect {
     )
   }
 
+  /// Gets the center point of the rect, rounded towards the top-left.
+  ///
+  /// The midpoint is computed with 64-bit arithmetic, such that rects
+  /// spanning large parts of the `i32` range don't overflow.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Rect;
+  /// let rect = Rect::from_ltrb(-3, -3, 0, 0);
+  /// let center = rect.center_point();
+  /// assert_eq!((center.x, center.y), (-2, -2));
+  ///
+  /// let rect = Rect::from_ltrb(i32::MIN, i32::MIN, i32::MAX, i32::MAX);
+  /// let center = rect.center_point();
+  /// assert_eq!((center.x, center.y), (-1, -1));
+  ///
+  /// let rect = Rect::from_ltrb(i32::MAX - 10, 0, i32::MAX, 10);
+  /// assert_eq!(rect.center_point().x, i32::MAX - 5);
+  /// ```
   #[must_use]
   pub fn center_point(&self) -> Point {
+    // The midpoint of two `i32` values always fits in an `i32`.
+    #[allow(clippy::cast_possible_truncation)]
+    let midpoint = |start: i32, end: i32| {
+      (i64::from(start) + i64::from(end)).div_euclid(2) as i32
+    };
+
     Point {
-      x: self.left + (self.width() / 2),
-      y: self.top + (self.height() / 2),
+      x: midpoint(self.left, self.right),
+      y: midpoint(self.top, self.bottom),
     }
   }
 
@@ -182,6 +269,140 @@ impl Rect {
       || other.y() + other.height() <= self.y())
   }
 
+  /// Gets whether the rect overlaps with the other rect. Rects that only
+  /// touch at their edges don't overlap.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Rect;
+  /// let rect = Rect::from_xy(0, 0, 100, 100);
+  /// assert!(rect.overlaps(&Rect::from_xy(50, 50, 100, 100)));
+  /// assert!(!rect.overlaps(&Rect::from_xy(100, 0, 100, 100)));
+  /// ```
+  #[must_use]
+  pub fn overlaps(&self, other: &Rect) -> bool {
+    self.has_overlap_x(other) && self.has_overlap_y(other)
+  }
+
+  /// Gets the area of the intersection between the rect and the other
+  /// rect. Returns 0 if the rects don't overlap.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Rect;
+  /// let rect = Rect::from_xy(0, 0, 100, 100);
+  /// assert_eq!(rect.intersection_area(&Rect::from_xy(50, 50, 100, 100)), 2500);
+  /// assert_eq!(rect.intersection_area(&Rect::from_xy(0, 100, 100, 100)), 0);
+  /// ```
+  #[must_use]
+  pub fn intersection_area(&self, other: &Rect) -> i64 {
+    let width = self.right.min(other.right) - self.left.max(other.left);
+    let height = self.bottom.min(other.bottom) - self.top.max(other.top);
+
+    if width <= 0 || height <= 0 {
+      return 0;
+    }
+
+    i64::from(width) * i64::from(height)
+  }
+
+  /// Gets the index of the best neighboring rect in the given direction.
+  ///
+  /// Candidates must lie in the given direction and overlap with the rect
+  /// on the perpendicular axis. The candidate with the smallest gap
+  /// between edges is preferred, then the one with the longest shared
+  /// edge.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::{Direction, Rect};
+  /// // Two monitors stacked to the right of the origin, where the upper
+  /// // one only overlaps the origin by 20px.
+  /// let origin = Rect::from_xy(0, 0, 1920, 1080);
+  /// let candidates = [
+  ///   Rect::from_xy(1920, -1060, 1920, 1080),
+  ///   Rect::from_xy(1920, 20, 1920, 1080),
+  ///   Rect::from_xy(0, 1080, 1920, 1080),
+  /// ];
+  ///
+  /// let neighbor = origin.neighbor_in_direction(&candidates, &Direction::Right);
+  /// assert_eq!(neighbor, Some(1));
+  ///
+  /// let neighbor = origin.neighbor_in_direction(&candidates, &Direction::Down);
+  /// assert_eq!(neighbor, Some(2));
+  ///
+  /// let neighbor = origin.neighbor_in_direction(&candidates, &Direction::Left);
+  /// assert_eq!(neighbor, None);
+  ///
+  /// // Three monitors in an L-shape, where the bottom monitor has no
+  /// // neighbor to its right.
+  /// let monitors = [
+  ///   Rect::from_xy(0, 0, 1920, 1080),
+  ///   Rect::from_xy(1920, 0, 1920, 1080),
+  ///   Rect::from_xy(0, 1080, 1920, 1080),
+  /// ];
+  ///
+  /// let neighbor = monitors[2].neighbor_in_direction(&monitors, &Direction::Up);
+  /// assert_eq!(neighbor, Some(0));
+  ///
+  /// let neighbor = monitors[2].neighbor_in_direction(&monitors, &Direction::Right);
+  /// assert_eq!(neighbor, None);
+  ///
+  /// let neighbor = monitors[1].neighbor_in_direction(&monitors, &Direction::Down);
+  /// assert_eq!(neighbor, None);
+  ///
+  /// let neighbor = monitors[1].neighbor_in_direction(&monitors, &Direction::Left);
+  /// assert_eq!(neighbor, Some(0));
+  /// ```
+  #[must_use]
+  pub fn neighbor_in_direction(
+    &self,
+    candidates: &[Rect],
+    direction: &Direction,
+  ) -> Option<usize> {
+    // Gets the gap between edges along the primary axis, and the length
+    // of the overlap along the perpendicular axis.
+    let score = |rect: &Rect| match direction {
+      Direction::Right => (
+        rect.left - self.right,
+        self.bottom.min(rect.bottom) - self.top.max(rect.top),
+      ),
+      Direction::Left => (
+        self.left - rect.right,
+        self.bottom.min(rect.bottom) - self.top.max(rect.top),
+      ),
+      Direction::Down => (
+        rect.top - self.bottom,
+        self.right.min(rect.right) - self.left.max(rect.left),
+      ),
+      Direction::Up => (
+        self.top - rect.bottom,
+        self.right.min(rect.right) - self.left.max(rect.left),
+      ),
+    };
+
+    let is_in_direction = |rect: &Rect| match direction {
+      Direction::Right => rect.x() > self.x(),
+      Direction::Left => rect.x() < self.x(),
+      Direction::Down => rect.y() > self.y(),
+      Direction::Up => rect.y() < self.y(),
+    };
+
+    candidates
+      .iter()
+      .enumerate()
+      .filter(|(_, rect)| is_in_direction(rect))
+      .map(|(index, rect)| (index, score(rect)))
+      .filter(|(_, (_, overlap))| *overlap > 0)
+      .min_by(|(_, (gap_a, overlap_a)), (_, (gap_b, overlap_b))| {
+        gap_a
+          .max(&0)
+          .cmp(gap_b.max(&0))
+          .then_with(|| overlap_b.cmp(overlap_a))
+      })
+      .map(|(index, _)| index)
+  }
+
   #[must_use]
   pub fn contains_point(&self, point: &Point) -> bool {
     let is_in_x = point.x >= self.left && point.x <= self.right;
@@ -189,17 +410,41 @@ impl Rect {
     is_in_x && is_in_y
   }
 
+  /// Gets the distance from the point to the nearest edge of the rect.
+  /// This is 0 if the point is within the rect.
+  ///
+  /// Unlike the distance to the center point, this isn't skewed by the
+  /// rect's size. A point just outside of a wide rect is closer to it
+  /// than to a small rect further away.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::{Point, Rect};
+  /// let wide = Rect::from_ltrb(0, 0, 1000, 100);
+  /// let small = Rect::from_ltrb(150, 150, 250, 250);
+  /// let point = Point { x: 100, y: 120 };
+  ///
+  /// assert!((wide.distance_to_point(&point) - 20.0).abs() < f32::EPSILON);
+  /// assert!(wide.distance_to_point(&point) < small.distance_to_point(&point));
+  ///
+  /// // By distance to the center point, the small rect would be nearer.
+  /// let center_distance =
+  ///   |rect: &Rect| rect.center_point().distance_to(&point);
+  /// assert!(center_distance(&small) < center_distance(&wide));
+  ///
+  /// assert_eq!(wide.distance_to_point(&Point { x: 500, y: 50 }), 0.0);
+  /// ```
   #[must_use]
+  #[allow(clippy::cast_precision_loss)]
   pub fn distance_to_point(&self, point: &Point) -> f32 {
-    let dx = (self.x() - point.x)
-      .abs()
-      .max((self.x() + self.width() - point.x).abs());
+    let dx = (i64::from(self.left) - i64::from(point.x))
+      .max(i64::from(point.x) - i64::from(self.right))
+      .max(0);
 
-    let dy = (self.y() - point.y)
-      .abs()
-      .max((self.y() + self.height() - point.y).abs());
+    let dy = (i64::from(self.top) - i64::from(point.y))
+      .max(i64::from(point.y) - i64::from(self.bottom))
+      .max(0);
 
-    #[allow(clippy::cast_precision_loss)]
-    ((dx * dx + dy * dy) as f32).sqrt()
+    (dx as f32).hypot(dy as f32)
   }
 }
@@ -4,7 +4,7 @@ use uuid::Uuid;
 use crate::{
   dtos::ContainerDto,
   parsed_config::{BindingModeConfig, ParsedConfig},
-  TilingDirection,
+  ActiveDragOperation, DragResult, Rect, TilingDirection,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -18,6 +18,14 @@ pub enum WmEvent {
   BindingModesChanged {
     new_binding_modes: Vec<BindingModeConfig>,
   },
+  DragEnded {
+    window_id: Uuid,
+    result: DragResult,
+  },
+  DragStarted {
+    window_id: Uuid,
+    operation: Option<ActiveDragOperation>,
+  },
   FocusChanged {
     focused_container: ContainerDto,
   },
@@ -46,6 +54,12 @@ pub enum WmEvent {
   WindowManaged {
     managed_window: ContainerDto,
   },
+  WindowMovedOrResizedEnded {
+    window_id: Uuid,
+    old_rect: Rect,
+    new_rect: Rect,
+    monitor_changed: bool,
+  },
   WindowUnmanaged {
     unmanaged_id: Uuid,
     unmanaged_handle: isize,
@@ -10,12 +10,14 @@ pub enum PlatformEvent {
   DisplaySettingsChanged,
   KeybindingTriggered(KeybindingConfig),
   MouseMove(MouseMoveEvent),
+  MouseWheel(MouseWheelEvent),
   WindowDestroyed(NativeWindow),
   WindowFocused(NativeWindow),
   WindowHidden(NativeWindow),
   WindowLocationChanged(NativeWindow),
   WindowMinimized(NativeWindow),
   WindowMinimizeEnded(NativeWindow),
+  WindowMovedOrResizedCanceled(NativeWindow),
   WindowMovedOrResizedEnd(NativeWindow),
   WindowMovedOrResizedStart(NativeWindow),
   WindowShown(NativeWindow),
@@ -32,6 +34,17 @@ pub struct MouseMoveEvent {
   pub is_mouse_down: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct MouseWheelEvent {
+  /// Location of mouse with 0,0 being the top-left corner of the primary
+  /// monitor.
+  pub point: Point,
+
+  /// Distance the wheel was rotated. A positive value indicates that the
+  /// wheel was rotated forward (away from the user).
+  pub delta: i16,
+}
+
 pub struct EventListener {
   pub event_rx: UnboundedReceiver<PlatformEvent>,
   event_window: EventWindow,
@@ -47,7 +60,7 @@ impl EventListener {
     let event_window = EventWindow::new(
       &event_tx,
       &config.keybindings,
-      config.general.focus_follows_cursor,
+      Self::needs_mouse_events(config),
     )?;
 
     Ok(Self {
@@ -83,6 +96,14 @@ impl EventListener {
 
     self
       .event_window
-      .update(keybindings, config.general.focus_follows_cursor && !paused);
+      .update(keybindings, Self::needs_mouse_events(config) && !paused);
+  }
+
+  /// Whether any enabled features in the user config rely on mouse
+  /// events.
+  fn needs_mouse_events(config: &ParsedConfig) -> bool {
+    config.general.focus_follows_cursor
+      || config.general.switch_workspace_on_scroll
+      || config.window_behavior.drag.edge_dwell_px > 0
   }
 }
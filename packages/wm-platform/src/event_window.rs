@@ -25,16 +25,17 @@ use windows::Win32::{
       PBT_APMRESUMEAUTOMATIC, PBT_APMRESUMESUSPEND, PBT_APMSUSPEND,
       RI_MOUSE_LEFT_BUTTON_DOWN, RI_MOUSE_LEFT_BUTTON_UP,
       RI_MOUSE_RIGHT_BUTTON_DOWN, RI_MOUSE_RIGHT_BUTTON_UP,
-      SPI_ICONVERTICALSPACING, SPI_SETWORKAREA, WM_DEVICECHANGE,
-      WM_DISPLAYCHANGE, WM_INPUT, WM_POWERBROADCAST, WM_SETTINGCHANGE,
+      RI_MOUSE_WHEEL, SPI_ICONVERTICALSPACING, SPI_SETWORKAREA,
+      WM_DEVICECHANGE, WM_DISPLAYCHANGE, WM_INPUT, WM_POWERBROADCAST,
+      WM_SETTINGCHANGE,
     },
   },
 };
 use wm_common::{KeybindingConfig, Point};
 
 use super::{
-  KeyboardHook, MouseMoveEvent, Platform, PlatformEvent, WindowEventHook,
-  FOREGROUND_INPUT_IDENTIFIER,
+  KeyboardHook, MouseMoveEvent, MouseWheelEvent, Platform, PlatformEvent,
+  WindowEventHook, FOREGROUND_INPUT_IDENTIFIER,
 };
 
 /// Global instance of sender for platform events.
@@ -288,6 +289,26 @@ fn handle_input_msg(
   let button_flags =
     unsafe { mouse_input.Anonymous.Anonymous.usButtonFlags };
 
+  // Wheel events are emitted separately and aren't throttled, since each
+  // one corresponds to a discrete scroll step. The same input can also
+  // carry button transitions, which are handled below.
+  if has_mouse_flag(button_flags, RI_MOUSE_WHEEL) {
+    #[allow(clippy::cast_possible_wrap)]
+    let delta =
+      unsafe { mouse_input.Anonymous.Anonymous.usButtonData } as i16;
+
+    let mut point = POINT { x: 0, y: 0 };
+    unsafe { GetCursorPos(&raw mut point) }?;
+
+    event_tx.send(PlatformEvent::MouseWheel(MouseWheelEvent {
+      point: Point {
+        x: point.x,
+        y: point.y,
+      },
+      delta,
+    }))?;
+  }
+
   let has_state_change = match button_flags {
     flags if has_mouse_flag(flags, RI_MOUSE_LEFT_BUTTON_DOWN) => {
       IS_L_MOUSE_DOWN.store(true, Ordering::Relaxed);
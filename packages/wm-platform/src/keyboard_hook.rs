@@ -38,7 +38,7 @@ use windows::Win32::{
 };
 use wm_common::KeybindingConfig;
 
-use super::PlatformEvent;
+use super::{PlatformEvent, WindowEventHook};
 
 /// Global instance of `KeyboardHook`.
 ///
@@ -459,14 +459,19 @@ extern "system" fn keyboard_hook_proc(
   // Get struct with keyboard input event.
   let input = unsafe { *(lparam.0 as *const KBDLLHOOKSTRUCT) };
 
+  #[allow(clippy::cast_possible_truncation)]
+  let vk_code = input.vkCode as u16;
+
   if let Some(hook) = KEYBOARD_HOOK.get() {
-    #[allow(clippy::cast_possible_truncation)]
-    let should_block = hook.handle_key_event(input.vkCode as u16);
+    let should_block = hook.handle_key_event(vk_code);
 
     if should_block {
       return LRESULT(1);
     }
   }
 
+  // Keys that are passed on can cancel an ongoing move or resize.
+  WindowEventHook::handle_key_down(vk_code);
+
   unsafe { CallNextHookEx(None, code, wparam, lparam) }
 }
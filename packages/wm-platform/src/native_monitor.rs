@@ -11,7 +11,9 @@ use windows::{
     },
     UI::{
       HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
-      WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME,
+      WindowsAndMessaging::{
+        EDD_GET_DEVICE_INTERFACE_NAME, MONITORINFOF_PRIMARY,
+      },
     },
   },
 };
@@ -30,6 +32,7 @@ struct MonitorInfo {
   hardware_id: Option<String>,
   rect: Rect,
   working_rect: Rect,
+  is_primary: bool,
   dpi: u32,
   scale_factor: f32,
 }
@@ -43,6 +46,26 @@ impl NativeMonitor {
     }
   }
 
+  /// Creates a monitor with the given rect, without querying the system
+  /// for its info. The working area spans the whole monitor.
+  #[cfg(feature = "test-utils")]
+  #[must_use]
+  pub fn from_rect(handle: isize, rect: Rect, is_primary: bool) -> Self {
+    Self {
+      handle,
+      info: OnceCell::from(MonitorInfo {
+        device_name: format!("\\\\.\\DISPLAY{handle}"),
+        device_path: None,
+        hardware_id: None,
+        working_rect: rect.clone(),
+        rect,
+        is_primary,
+        dpi: 96,
+        scale_factor: 1.,
+      }),
+    }
+  }
+
   pub fn device_name(&self) -> anyhow::Result<&String> {
     self.monitor_info().map(|info| &info.device_name)
   }
@@ -63,6 +86,10 @@ impl NativeMonitor {
     self.monitor_info().map(|info| &info.working_rect)
   }
 
+  pub fn is_primary(&self) -> anyhow::Result<bool> {
+    self.monitor_info().map(|info| info.is_primary)
+  }
+
   pub fn dpi(&self) -> anyhow::Result<u32> {
     self.monitor_info().map(|info| info.dpi)
   }
@@ -153,12 +180,16 @@ impl NativeMonitor {
         rc_work.bottom,
       );
 
+      let is_primary =
+        monitor_info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0;
+
       Ok(MonitorInfo {
         device_name,
         device_path,
         hardware_id,
         rect,
         working_rect,
+        is_primary,
         dpi,
         scale_factor,
       })
@@ -43,7 +43,7 @@ use windows::{
 };
 use wm_common::{
   Color, CornerStyle, Delta, HideMethod, LengthValue, Memo, OpacityValue,
-  Rect, RectDelta, WindowState,
+  Point, Rect, RectDelta, WindowState,
 };
 
 use super::COM_INIT;
@@ -52,6 +52,10 @@ use super::COM_INIT;
 /// process.
 pub const FOREGROUND_INPUT_IDENTIFIER: u32 = 6379;
 
+/// Distance (in pixels) inside the edges of a window's frame that's
+/// treated as a resize handle.
+const RESIZE_HANDLE_INSET: i32 = 4;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ZOrder {
   Normal,
@@ -88,6 +92,25 @@ impl NativeWindow {
     }
   }
 
+  /// Creates a window with the given process and class name, without
+  /// querying the system for them. The title is empty.
+  #[cfg(feature = "test-utils")]
+  #[must_use]
+  pub fn with_names(
+    handle: isize,
+    process_name: &str,
+    class_name: &str,
+  ) -> Self {
+    let window = Self::new(handle);
+    let preset = |name: &&str| Ok((*name).to_string());
+
+    _ = window.title.get_or_init(preset, &"");
+    _ = window.process_name.get_or_init(preset, &process_name);
+    _ = window.class_name.get_or_init(preset, &class_name);
+
+    window
+  }
+
   /// Gets the window's title. If the window is invalid, returns an empty
   /// string.
   ///
@@ -295,6 +318,35 @@ impl NativeWindow {
     self.has_window_style(WS_THICKFRAME)
   }
 
+  /// Whether the given point is over one of the window's resize handles
+  /// (i.e. its borders or corners).
+  ///
+  /// This is classified by the point's position relative to the window's
+  /// frame, rather than by sending `WM_NCHITTEST` to the window, which
+  /// blocks if the window is slow to respond. Points over the invisible
+  /// resize borders around the frame, or just inside its edges, are
+  /// considered resize handles.
+  pub fn is_resize_handle_at(
+    &self,
+    point: &Point,
+  ) -> anyhow::Result<bool> {
+    if !self.is_resizable() {
+      return Ok(false);
+    }
+
+    let frame = self.refresh_frame_position()?;
+    let border = self.border_position()?;
+
+    let inner_frame = Rect::from_ltrb(
+      frame.left + RESIZE_HANDLE_INSET,
+      frame.top + RESIZE_HANDLE_INSET,
+      frame.right - RESIZE_HANDLE_INSET,
+      frame.bottom - RESIZE_HANDLE_INSET,
+    );
+
+    Ok(border.contains_point(point) && !inner_frame.contains_point(point))
+  }
+
   /// Whether the window is fullscreen.
   ///
   /// Returns `false` if the window is maximized.
@@ -500,6 +552,18 @@ impl NativeWindow {
       .get_or_init(Self::updated_frame_position, self)
   }
 
+  /// Gets the aspect ratio (width divided by height) of the window's
+  /// frame. Returns `None` if the window has no height.
+  #[allow(clippy::cast_precision_loss)]
+  pub fn aspect_ratio(&self) -> anyhow::Result<Option<f32>> {
+    let frame = self.frame_position()?;
+
+    Ok(
+      (frame.height() > 0)
+        .then(|| frame.width() as f32 / frame.height() as f32),
+    )
+  }
+
   /// Updates the cached frame position.
   pub fn refresh_frame_position(&self) -> anyhow::Result<Rect> {
     _ = self.refresh_border_position()?;
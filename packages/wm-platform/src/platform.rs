@@ -66,6 +66,19 @@ impl Platform {
     NativeWindow::new(handle.0)
   }
 
+  /// Whether the window is part of the desktop background.
+  ///
+  /// Besides the wallpaper window, the shell can host the desktop in a
+  /// `WorkerW` window (e.g. after the wallpaper has been changed).
+  #[must_use]
+  pub fn is_desktop_background(window: &NativeWindow) -> bool {
+    *window == Self::desktop_window()
+      || *window == NativeWindow::new(unsafe { GetDesktopWindow() }.0)
+      || window
+        .class_name()
+        .is_ok_and(|class_name| class_name == "WorkerW")
+  }
+
   /// Gets a vector of available monitors as `NativeMonitor` instances
   /// sorted from left-to-right and top-to-bottom.
   ///
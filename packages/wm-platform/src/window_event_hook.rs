@@ -1,4 +1,7 @@
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{
+  atomic::{AtomicBool, Ordering},
+  Arc, Mutex, OnceLock,
+};
 
 use anyhow::Result;
 use tokio::sync::mpsc;
@@ -7,6 +10,7 @@ use windows::Win32::{
   Foundation::HWND,
   UI::{
     Accessibility::{SetWinEventHook, UnhookWinEvent, HWINEVENTHOOK},
+    Input::KeyboardAndMouse::VK_ESCAPE,
     WindowsAndMessaging::{
       EVENT_OBJECT_CLOAKED, EVENT_OBJECT_DESTROY, EVENT_OBJECT_HIDE,
       EVENT_OBJECT_LOCATIONCHANGE, EVENT_OBJECT_NAMECHANGE,
@@ -25,6 +29,12 @@ use super::{NativeWindow, PlatformEvent};
 /// For use with hook procedure.
 static WIN_EVENT_HOOK: OnceLock<Arc<WindowEventHook>> = OnceLock::new();
 
+/// Whether a window is currently being moved or resized.
+static IS_MOVE_SIZE_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Whether escape was pressed during the current move or resize.
+static IS_MOVE_SIZE_CANCELED: AtomicBool = AtomicBool::new(false);
+
 #[derive(Debug)]
 pub struct WindowEventHook {
   event_tx: mpsc::UnboundedSender<PlatformEvent>,
@@ -126,9 +136,14 @@ impl WindowEventHook {
         PlatformEvent::WindowMinimizeEnded(window)
       }
       EVENT_SYSTEM_MOVESIZEEND => {
-        PlatformEvent::WindowMovedOrResizedEnd(window)
+        if Self::end_move_size() {
+          PlatformEvent::WindowMovedOrResizedCanceled(window)
+        } else {
+          PlatformEvent::WindowMovedOrResizedEnd(window)
+        }
       }
       EVENT_SYSTEM_MOVESIZESTART => {
+        Self::start_move_size();
         PlatformEvent::WindowMovedOrResizedStart(window)
       }
       EVENT_OBJECT_SHOW | EVENT_OBJECT_UNCLOAKED => {
@@ -143,6 +158,31 @@ impl WindowEventHook {
     }
   }
 
+  /// Marks a move or resize as ongoing and not canceled.
+  fn start_move_size() {
+    IS_MOVE_SIZE_ACTIVE.store(true, Ordering::Relaxed);
+    IS_MOVE_SIZE_CANCELED.store(false, Ordering::Relaxed);
+  }
+
+  /// Returns whether the move or resize was canceled.
+  fn end_move_size() -> bool {
+    IS_MOVE_SIZE_ACTIVE.store(false, Ordering::Relaxed);
+    IS_MOVE_SIZE_CANCELED.swap(false, Ordering::Relaxed)
+  }
+
+  /// Records a key press that was passed on by the keyboard hook.
+  ///
+  /// Escape exits an ongoing move or resize. The key has usually been
+  /// released by the time the end event arrives, so it's recorded here
+  /// rather than polled on the end event.
+  pub(crate) fn handle_key_down(vk_code: u16) {
+    if vk_code == VK_ESCAPE.0
+      && IS_MOVE_SIZE_ACTIVE.load(Ordering::Relaxed)
+    {
+      IS_MOVE_SIZE_CANCELED.store(true, Ordering::Relaxed);
+    }
+  }
+
   /// Stops the window event hook and unhooks from all window events.
   ///
   /// # Panics
@@ -183,3 +223,28 @@ extern "system" fn window_event_hook_proc(
     hook.handle_event(event_type, handle.0);
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use windows::Win32::UI::Input::KeyboardAndMouse::{VK_ESCAPE, VK_SPACE};
+
+  use super::WindowEventHook;
+
+  #[test]
+  fn tracks_escape_during_move_size() {
+    // Escape before the drag has no effect.
+    WindowEventHook::handle_key_down(VK_ESCAPE.0);
+    WindowEventHook::start_move_size();
+    assert!(!WindowEventHook::end_move_size());
+
+    // Escape mid-drag cancels it, even if released before the end event.
+    WindowEventHook::start_move_size();
+    WindowEventHook::handle_key_down(VK_SPACE.0);
+    WindowEventHook::handle_key_down(VK_ESCAPE.0);
+    assert!(WindowEventHook::end_move_size());
+
+    // The cancel doesn't carry over to the next drag.
+    WindowEventHook::start_move_size();
+    assert!(!WindowEventHook::end_move_size());
+  }
+}
@@ -0,0 +1,125 @@
+use tracing::info;
+
+use super::equalize_tiling_children;
+use crate::{
+  models::DirectionContainer, traits::CommonGetters, wm_state::WmState,
+};
+
+/// Sets the tiling children of the container to equal sizes, recursing
+/// into nested split containers. Balancing a workspace evens out its
+/// whole tree.
+///
+/// Only tiling children are counted, so floating windows are left as-is.
+pub fn balance_container(
+  container: &DirectionContainer,
+  state: &mut WmState,
+) {
+  info!("Balancing container: {}", container.id());
+
+  let direction_containers = container
+    .self_and_descendants()
+    .filter_map(|descendant| descendant.as_direction_container().ok());
+
+  for direction_container in direction_containers {
+    equalize_tiling_children(&direction_container);
+  }
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(container.clone());
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, TilingDirection, WindowState};
+
+  use super::*;
+  use crate::{
+    test_utils::{
+      attach, non_tiling_window, split, tiling_window, wm_state, workspace,
+    },
+    traits::{TilingSizeGetters, WindowGetters},
+  };
+
+  #[test]
+  fn balances_nested_splits() {
+    // Layout: H[1 V[2 H[3 4 5]]] with a floating window.
+    let workspace = workspace(TilingDirection::Horizontal);
+    let outer_split = split(TilingDirection::Vertical);
+    let inner_split = split(TilingDirection::Horizontal);
+    let floating_window = non_tiling_window(
+      6,
+      WindowState::Floating(FloatingStateConfig::default()),
+    );
+
+    attach(tiling_window(1), workspace.clone());
+    attach(outer_split.clone(), workspace.clone());
+    attach(floating_window.clone(), workspace.clone());
+    attach(tiling_window(2), outer_split.clone());
+    attach(inner_split.clone(), outer_split.clone());
+
+    for handle in [3, 4, 5] {
+      attach(tiling_window(handle), inner_split.clone());
+    }
+
+    let mut state = wm_state(&workspace);
+
+    let direction_containers = workspace
+      .self_and_descendants()
+      .filter_map(|descendant| descendant.as_direction_container().ok())
+      .collect::<Vec<_>>();
+
+    for direction_container in &direction_containers {
+      for (child, size) in
+        direction_container.tiling_children().zip([0.6, 0.3, 0.1])
+      {
+        child.set_tiling_size(size);
+      }
+    }
+
+    let floating_placement = floating_window.floating_placement();
+
+    balance_container(&workspace.clone().into(), &mut state);
+
+    for direction_container in &direction_containers {
+      let sizes = direction_container
+        .tiling_children()
+        .map(|child| child.tiling_size())
+        .collect::<Vec<_>>();
+
+      #[allow(clippy::cast_precision_loss)]
+      let target_size = 1. / sizes.len() as f32;
+
+      assert!(sizes.iter().all(|size| (size - target_size).abs() < 1e-6));
+      assert!((sizes.iter().sum::<f32>() - 1.).abs() < 1e-6);
+    }
+
+    assert_eq!(floating_window.floating_placement(), floating_placement);
+    assert_eq!(
+      floating_window.parent().map(|parent| parent.id()),
+      Some(workspace.id())
+    );
+    assert_eq!(floating_window.index(), 2);
+  }
+
+  #[test]
+  fn leaves_single_child_as_is() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let split = split(TilingDirection::Vertical);
+    let window = tiling_window(1);
+
+    attach(split.clone(), workspace.clone());
+    attach(window.clone(), split.clone());
+
+    let mut state = wm_state(&workspace);
+
+    balance_container(&split.clone().into(), &mut state);
+
+    assert!((window.tiling_size() - 1.).abs() < f32::EPSILON);
+    assert!((split.tiling_size() - 1.).abs() < f32::EPSILON);
+    assert_eq!(
+      window.parent().map(|parent| parent.id()),
+      Some(split.id())
+    );
+  }
+}
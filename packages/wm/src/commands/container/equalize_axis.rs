@@ -0,0 +1,127 @@
+use anyhow::Context;
+use wm_common::TilingDirection;
+
+use super::equalize_tiling_children;
+use crate::{
+  models::Container,
+  traits::{CommonGetters, TilingDirectionGetters},
+  wm_state::WmState,
+};
+
+/// Equalizes the tiling sizes of containers along the given axis within
+/// the container's workspace.
+///
+/// Only the children of direction containers with a matching tiling
+/// direction are resized. For example, equalizing the horizontal axis of
+/// a grid evens out its columns while leaving the rows as-is.
+pub fn equalize_axis(
+  container: &Container,
+  tiling_direction: &TilingDirection,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let workspace = container.workspace().context("No workspace.")?;
+
+  let direction_containers = workspace
+    .self_and_descendants()
+    .filter_map(|descendant| descendant.as_direction_container().ok())
+    .filter(|direction_container| {
+      direction_container.tiling_direction() == *tiling_direction
+    });
+
+  for direction_container in direction_containers {
+    equalize_tiling_children(&direction_container);
+  }
+
+  state.pending_sync.queue_container_to_redraw(workspace);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    test_utils::{attach, split, tiling_window, wm_state, workspace},
+    traits::TilingSizeGetters,
+  };
+
+  #[test]
+  fn only_equalizes_matching_direction() {
+    // A grid of two columns, each with three rows.
+    let workspace = workspace(TilingDirection::Horizontal);
+    let columns = [
+      split(TilingDirection::Vertical),
+      split(TilingDirection::Vertical),
+    ];
+
+    for (column, handles) in columns.iter().zip([[0, 1, 2], [3, 4, 5]]) {
+      attach(column.clone(), workspace.clone());
+
+      for handle in handles {
+        attach(tiling_window(handle), column.clone());
+      }
+    }
+
+    let mut state = wm_state(&workspace);
+
+    let skew_sizes = |containers: Vec<_>, sizes: &[f32]| {
+      for (container, size) in containers.iter().zip(sizes) {
+        TilingSizeGetters::set_tiling_size(container, *size);
+      }
+    };
+
+    skew_sizes(workspace.tiling_children().collect(), &[0.8, 0.2]);
+    for column in &columns {
+      skew_sizes(column.tiling_children().collect(), &[0.5, 0.3, 0.2]);
+    }
+
+    let column_sizes = || {
+      workspace
+        .tiling_children()
+        .map(|column| column.tiling_size())
+        .collect::<Vec<_>>()
+    };
+
+    let row_sizes = || {
+      columns
+        .iter()
+        .map(|column| {
+          column
+            .tiling_children()
+            .map(|row| row.tiling_size())
+            .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let is_equal = |sizes: &[f32], target: f32| {
+      sizes
+        .iter()
+        .all(|size| (size - target).abs() < f32::EPSILON)
+    };
+
+    // Equalizing columns leaves the rows as-is.
+    equalize_axis(
+      &workspace.clone().into(),
+      &TilingDirection::Horizontal,
+      &mut state,
+    )
+    .unwrap();
+
+    assert!(is_equal(&column_sizes(), 0.5));
+    assert_eq!(row_sizes(), vec![vec![0.5, 0.3, 0.2]; 2]);
+
+    // Equalizing rows leaves the columns as-is.
+    equalize_axis(
+      &workspace.clone().into(),
+      &TilingDirection::Vertical,
+      &mut state,
+    )
+    .unwrap();
+
+    assert!(is_equal(&column_sizes(), 0.5));
+    for sizes in row_sizes() {
+      assert!(is_equal(&sizes, 1. / 3.));
+    }
+  }
+}
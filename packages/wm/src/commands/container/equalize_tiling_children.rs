@@ -0,0 +1,60 @@
+use crate::{
+  models::DirectionContainer,
+  traits::{CommonGetters, TilingSizeGetters},
+};
+
+/// Sets the tiling children of the container to equal sizes.
+///
+/// The rounding error is added to the last tiling child, so that the
+/// sizes still sum to 1 when the child count doesn't divide evenly.
+#[allow(clippy::cast_precision_loss)]
+pub fn equalize_tiling_children(container: &DirectionContainer) {
+  let tiling_children = container.tiling_children().collect::<Vec<_>>();
+
+  let Some((last_child, other_children)) = tiling_children.split_last()
+  else {
+    return;
+  };
+
+  let target_size = 1.0 / tiling_children.len() as f32;
+
+  for tiling_child in other_children {
+    tiling_child.set_tiling_size(target_size);
+  }
+
+  last_child
+    .set_tiling_size(1. - target_size * other_children.len() as f32);
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::test_utils::{attach, tiling_window, workspace};
+
+  #[test]
+  fn sizes_sum_to_one() {
+    for child_count in 1..=7 {
+      let workspace = workspace(TilingDirection::Horizontal);
+
+      for handle in 0..child_count {
+        attach(tiling_window(handle), workspace.clone());
+      }
+
+      // Skew the sizes so that the equalizing has an effect.
+      if let Some(first_child) = workspace.tiling_children().next() {
+        first_child.set_tiling_size(first_child.tiling_size() * 2.);
+      }
+
+      equalize_tiling_children(&workspace.clone().into());
+
+      let total_size = workspace
+        .tiling_children()
+        .map(|child| child.tiling_size())
+        .sum::<f32>();
+
+      assert!((total_size - 1.).abs() < f32::EPSILON);
+    }
+  }
+}
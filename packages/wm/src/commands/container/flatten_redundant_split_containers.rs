@@ -0,0 +1,100 @@
+use super::flatten_split_container;
+use crate::{models::Container, traits::CommonGetters};
+
+/// Flattens any split containers with a single child within the given
+/// container's subtree (including the container itself).
+///
+/// The single child takes the place and tiling size of the split
+/// container. Split containers that are direct children of a workspace
+/// are kept.
+///
+/// For example:
+/// ```ignore,compile_fail
+/// H[1 V[H[V[2]]]] -> H[1 V[2]]
+/// ```
+pub fn flatten_redundant_split_containers(
+  container: &Container,
+) -> anyhow::Result<()> {
+  let split_containers = container
+    .self_and_descendants()
+    .filter_map(|descendant| descendant.as_split().cloned())
+    .collect::<Vec<_>>();
+
+  for split_container in split_containers {
+    let is_redundant = split_container.child_count() == 1
+      && split_container
+        .parent()
+        .is_some_and(|parent| !parent.is_workspace());
+
+    if is_redundant {
+      flatten_split_container(split_container)?;
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::{
+    models::Container,
+    test_utils::{attach, split, tiling_window, workspace},
+    traits::TilingSizeGetters,
+  };
+
+  #[test]
+  fn collapses_three_level_nesting() {
+    // H[1 V[2 H[V[H[3]]]]] -> H[1 V[2 3]]
+    let workspace = workspace(TilingDirection::Horizontal);
+    let outer = split(TilingDirection::Vertical);
+    let level_1 = split(TilingDirection::Horizontal);
+    let level_2 = split(TilingDirection::Vertical);
+    let level_3 = split(TilingDirection::Horizontal);
+    let window_1 = tiling_window(1);
+    let window_2 = tiling_window(2);
+    let window_3 = tiling_window(3);
+
+    attach(window_1.clone(), workspace.clone());
+    attach(outer.clone(), workspace.clone());
+    attach(window_2.clone(), outer.clone());
+    attach(level_1.clone(), outer.clone());
+    attach(level_2.clone(), level_1.clone());
+    attach(level_3.clone(), level_2.clone());
+    attach(window_3.clone(), level_3.clone());
+
+    let nested_size = level_1.tiling_size();
+
+    flatten_redundant_split_containers(&outer.clone().into()).unwrap();
+
+    let children = outer.children().into_iter().collect::<Vec<_>>();
+    assert_eq!(
+      children,
+      vec![
+        Container::from(window_2.clone()),
+        Container::from(window_3.clone())
+      ]
+    );
+    assert_eq!(window_3.parent(), Some(outer.clone().into()));
+    assert!((window_3.tiling_size() - nested_size).abs() < f32::EPSILON);
+    assert!(level_1.parent().is_none());
+    assert!(level_3.parent().is_none());
+  }
+
+  #[test]
+  fn keeps_workspace_direct_split() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let outer = split(TilingDirection::Vertical);
+    let window = tiling_window(1);
+
+    attach(outer.clone(), workspace.clone());
+    attach(window.clone(), outer.clone());
+
+    flatten_redundant_split_containers(&workspace.clone().into()).unwrap();
+
+    assert_eq!(outer.parent(), Some(workspace.into()));
+    assert_eq!(window.parent(), Some(outer.into()));
+  }
+}
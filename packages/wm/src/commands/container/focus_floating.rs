@@ -0,0 +1,62 @@
+use anyhow::Context;
+use wm_common::WindowState;
+
+use super::{focus_in_direction::should_skip, set_focused_descendant};
+use crate::{
+  models::Container,
+  traits::{CommonGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Cycles focus to the next or previous floating window in the origin
+/// container's workspace, wrapping around at either end.
+///
+/// Windows are cycled in workspace order rather than focus order, since
+/// the focus order changes on every cycle. If the origin container isn't
+/// a floating window, the first (or last) floating window is focused.
+///
+/// Does nothing if there is no other floating window to focus.
+pub fn focus_floating(
+  origin_container: &Container,
+  reverse: bool,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let workspace = origin_container.workspace().context("No workspace.")?;
+
+  let floating_windows = workspace
+    .children()
+    .into_iter()
+    .filter(|child| {
+      child.as_non_tiling_window().is_some_and(|window| {
+        matches!(window.state(), WindowState::Floating(_))
+      }) && (child.id() == origin_container.id()
+        || !should_skip(child, config))
+    })
+    .collect::<Vec<_>>();
+
+  let origin_index = floating_windows
+    .iter()
+    .position(|window| window.id() == origin_container.id());
+
+  let target_index = match (origin_index, reverse) {
+    (Some(index), false) => (index + 1) % floating_windows.len(),
+    (Some(index), true) => {
+      (index + floating_windows.len() - 1) % floating_windows.len()
+    }
+    (None, false) => 0,
+    (None, true) => floating_windows.len().saturating_sub(1),
+  };
+
+  let focus_target = floating_windows
+    .get(target_index)
+    .filter(|target| target.id() != origin_container.id());
+
+  if let Some(focus_target) = focus_target {
+    set_focused_descendant(focus_target, None);
+    state.pending_sync.queue_focus_change().queue_cursor_jump();
+  }
+
+  Ok(())
+}
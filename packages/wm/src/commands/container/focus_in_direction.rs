@@ -1,10 +1,15 @@
 use anyhow::Context;
-use wm_common::{Direction, TilingDirection, WindowState};
+use wm_common::{
+  CrossMonitorFocus, Direction, Point, TilingDirection, WindowState,
+};
 
 use super::set_focused_descendant;
 use crate::{
-  models::{Container, TilingContainer},
-  traits::{CommonGetters, TilingDirectionGetters, WindowGetters},
+  models::{Container, TilingContainer, Workspace},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters, WindowGetters,
+  },
+  user_config::UserConfig,
   wm_state::WmState,
 };
 
@@ -12,29 +17,61 @@ pub fn focus_in_direction(
   origin_container: &Container,
   direction: &Direction,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   let focus_target = match origin_container {
     Container::TilingWindow(_) => {
       // If a suitable focus target isn't found in the current workspace,
-      // attempt to find a workspace in the given direction.
-      tiling_focus_target(origin_container, direction)?.map_or_else(
-        || workspace_focus_target(origin_container, direction, state),
-        |container| Ok(Some(container)),
-      )?
+      // attempt to wrap around within the workspace (if enabled), and
+      // otherwise find a workspace in the given direction.
+      tiling_focus_target(origin_container, direction, config)?
+        .or_else(|| {
+          if config.value.general.focus.wrap_workspace {
+            wrap_focus_target(origin_container, direction)
+          } else {
+            None
+          }
+        })
+        .map_or_else(
+          || {
+            workspace_focus_target(
+              origin_container,
+              direction,
+              state,
+              config,
+            )
+          },
+          |container| Ok(Some(container)),
+        )?
     }
     Container::NonTilingWindow(ref non_tiling_window) => {
       match non_tiling_window.state() {
         WindowState::Floating(_) => {
-          floating_focus_target(origin_container, direction)
+          floating_focus_target(origin_container, direction, config)?
         }
-        WindowState::Fullscreen(_) => {
-          workspace_focus_target(origin_container, direction, state)?
+        WindowState::Fullscreen(_) => workspace_focus_target(
+          origin_container,
+          direction,
+          state,
+          config,
+        )?,
+        // Hand off focus to another workspace from a minimized window,
+        // since the workspace might only have minimized windows.
+        WindowState::Minimized
+          if config.value.general.focus.skip_minimized =>
+        {
+          workspace_focus_target(
+            origin_container,
+            direction,
+            state,
+            config,
+          )?
         }
         _ => None,
       }
     }
     Container::Workspace(_) => {
-      workspace_focus_target(origin_container, direction, state)?
+      workspace_focus_target(origin_container, direction, state, config)?
     }
     _ => None,
   };
@@ -48,32 +85,87 @@ pub fn focus_in_direction(
   Ok(())
 }
 
+/// Gets a focus target amongst the floating siblings of the origin
+/// container.
+///
+/// Picks the floating window whose center point is nearest along the
+/// axis of the given direction. Wraps around to the farthest window in the
+/// opposite direction if none are found.
 fn floating_focus_target(
   origin_container: &Container,
   direction: &Direction,
-) -> Option<Container> {
-  let is_floating = |sibling: &Container| {
-    sibling.as_non_tiling_window().is_some_and(|window| {
-      matches!(window.state(), WindowState::Floating(_))
+  config: &UserConfig,
+) -> anyhow::Result<Option<Container>> {
+  let origin_center = origin_container.to_rect()?.center_point();
+
+  // Get the floating siblings with their offset from the origin.
+  let floating_siblings = origin_container
+    .siblings()
+    .filter(|sibling| {
+      sibling.as_non_tiling_window().is_some_and(|window| {
+        matches!(window.state(), WindowState::Floating(_))
+      }) && !should_skip(sibling, config)
     })
+    .collect::<Vec<_>>();
+
+  let offsets = floating_siblings
+    .iter()
+    .map(|sibling| {
+      let center = sibling.to_rect()?.center_point();
+      anyhow::Ok(center - origin_center.clone())
+    })
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
+  Ok(
+    nearest_offset_in_direction(&offsets, direction)
+      .map(|index| floating_siblings[index].clone()),
+  )
+}
+
+/// Gets the index of the offset that's nearest along the axis of the
+/// given direction. Ties along the axis are broken by the distance to the
+/// origin, so that a window directly in line is preferred over one that's
+/// off to the side.
+///
+/// Wraps around to the farthest offset in the opposite direction if none
+/// are in the given direction.
+fn nearest_offset_in_direction(
+  offsets: &[Point],
+  direction: &Direction,
+) -> Option<usize> {
+  // Distance along the given direction. Positive if the offset is in the
+  // given direction.
+  let distance_in_direction = |offset: &Point| match direction {
+    Direction::Left => -offset.x,
+    Direction::Right => offset.x,
+    Direction::Up => -offset.y,
+    Direction::Down => offset.y,
   };
 
-  let mut floating_siblings =
-    origin_container.siblings().filter(is_floating);
-
-  // Wrap if next/previous floating window is not found.
-  match direction {
-    Direction::Left => origin_container
-      .next_siblings()
-      .find(is_floating)
-      .or_else(|| floating_siblings.last()),
-    Direction::Right => origin_container
-      .prev_siblings()
-      .find(is_floating)
-      .or_else(|| floating_siblings.next()),
-    // Cannot focus vertically from a floating window.
-    _ => None,
-  }
+  let origin = Point { x: 0, y: 0 };
+
+  let compare = |offset_a: &Point, offset_b: &Point| {
+    distance_in_direction(offset_a)
+      .cmp(&distance_in_direction(offset_b))
+      .then_with(|| {
+        offset_a
+          .distance_to(&origin)
+          .total_cmp(&offset_b.distance_to(&origin))
+      })
+  };
+
+  let indexed_offsets = offsets.iter().enumerate();
+
+  indexed_offsets
+    .clone()
+    .filter(|(_, offset)| distance_in_direction(offset) > 0)
+    .min_by(|(_, offset_a), (_, offset_b)| compare(offset_a, offset_b))
+    // Wrap if no offset is found in the given direction.
+    .or_else(|| {
+      indexed_offsets
+        .min_by(|(_, offset_a), (_, offset_b)| compare(offset_a, offset_b))
+    })
+    .map(|(index, _)| index)
 }
 
 /// Gets a focus target within the current workspace. Traverse upwards from
@@ -81,6 +173,7 @@ fn floating_focus_target(
 fn tiling_focus_target(
   origin_container: &Container,
   direction: &Direction,
+  config: &UserConfig,
 ) -> anyhow::Result<Option<Container>> {
   let tiling_direction = TilingDirection::from_direction(direction);
   let mut origin_or_ancestor = origin_container.clone();
@@ -100,12 +193,15 @@ fn tiling_focus_target(
     }
 
     // Get the next/prev tiling sibling depending on the tiling direction.
+    // Siblings that should be skipped are scanned past.
     let focus_target = match direction {
       Direction::Up | Direction::Left => origin_or_ancestor
         .prev_siblings()
+        .filter(|c| !should_skip(c, config))
         .find_map(|c| c.as_tiling_container().ok()),
       _ => origin_or_ancestor
         .next_siblings()
+        .filter(|c| !should_skip(c, config))
         .find_map(|c| c.as_tiling_container().ok()),
     };
 
@@ -126,6 +222,59 @@ fn tiling_focus_target(
   Ok(None)
 }
 
+/// Whether the container should be skipped when focusing in a direction.
+///
+/// Windows that are natively minimized are skipped if enabled in the user
+/// config, as are split containers that only contain such windows.
+pub(super) fn should_skip(
+  container: &Container,
+  config: &UserConfig,
+) -> bool {
+  if !config.value.general.focus.skip_minimized {
+    return false;
+  }
+
+  let is_minimized = |container: &Container| {
+    container
+      .as_window_container()
+      .is_ok_and(|window| window.native().is_minimized().unwrap_or(false))
+  };
+
+  match container {
+    Container::Split(split) => split
+      .descendants()
+      .filter(|descendant| descendant.as_window_container().is_ok())
+      .all(|descendant| is_minimized(&descendant)),
+    _ => is_minimized(container),
+  }
+}
+
+/// Gets a focus target by wrapping around to the opposite edge of the
+/// current workspace.
+///
+/// Wraps within the outermost ancestor that tiles along the axis of the
+/// given direction. This is the workspace itself, unless the workspace's
+/// tiling direction is perpendicular to the given direction.
+fn wrap_focus_target(
+  origin_container: &Container,
+  direction: &Direction,
+) -> Option<Container> {
+  let tiling_direction = TilingDirection::from_direction(direction);
+
+  let wrap_container = origin_container
+    .ancestors()
+    .filter_map(|ancestor| ancestor.as_direction_container().ok())
+    .filter(|ancestor| ancestor.tiling_direction() == tiling_direction)
+    .last()?;
+
+  wrap_container
+    .descendant_in_direction(&direction.inverse())
+    .map(Container::from)
+    // Avoid wrapping to the origin container (e.g. if it's the only
+    // window in the workspace).
+    .filter(|target| target.id() != origin_container.id())
+}
+
 /// Gets a focus target outside of the current workspace in the given
 /// direction.
 ///
@@ -136,6 +285,7 @@ fn workspace_focus_target(
   origin_container: &Container,
   direction: &Direction,
   state: &WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<Option<Container>> {
   let monitor = origin_container.monitor().context("No monitor.")?;
 
@@ -145,15 +295,25 @@ fn workspace_focus_target(
 
   let focused_fullscreen = target_workspace
     .as_ref()
-    .and_then(|workspace| workspace.descendant_focus_order().next())
-    .filter(|focused| match focused {
-      Container::NonTilingWindow(window) => {
-        matches!(window.state(), WindowState::Fullscreen(_))
-      }
-      _ => false,
+    .and_then(Workspace::focused_fullscreen_window)
+    .map(Into::into);
+
+  // Get the most recently focused window in the workspace if enabled.
+  // Otherwise, this falls back to the window nearest to the edge that's
+  // being crossed.
+  let recent_window = target_workspace
+    .as_ref()
+    .filter(|_| {
+      config.value.general.focus.cross_monitor == CrossMonitorFocus::Recent
+    })
+    .and_then(|workspace| {
+      workspace
+        .descendant_focus_order()
+        .find(|descendant| descendant.as_window_container().is_ok())
     });
 
   let focus_target = focused_fullscreen
+    .or(recent_window)
     .or_else(|| {
       target_workspace.as_ref().and_then(|workspace| {
         workspace
@@ -161,7 +321,107 @@ fn workspace_focus_target(
           .map(Into::into)
       })
     })
+    .or_else(|| {
+      // Fall back to the top-most floating window if the workspace has no
+      // tiling windows. Floating windows are brought to front on focus, so
+      // the most recently focused one is top-most.
+      target_workspace.as_ref().and_then(|workspace| {
+        workspace.descendant_focus_order().find(|descendant| {
+          matches!(
+            descendant,
+            Container::NonTilingWindow(window)
+              if matches!(window.state(), WindowState::Floating(_))
+          )
+        })
+      })
+    })
     .or(target_workspace.map(Into::into));
 
   Ok(focus_target)
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, ParsedConfig, Rect};
+
+  use super::*;
+  use crate::test_utils::{
+    attach, monitor, non_tiling_window, tiling_window, wm_state, workspace,
+  };
+
+  #[test]
+  fn prefers_nearest_along_axis() {
+    // The first offset is farther away overall, but nearer horizontally.
+    let offsets = [Point { x: 100, y: 300 }, Point { x: 150, y: 0 }];
+
+    assert_eq!(
+      nearest_offset_in_direction(&offsets, &Direction::Right),
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn breaks_axis_ties_by_distance() {
+    let offsets = [Point { x: 0, y: -100 }, Point { x: -50, y: -100 }];
+
+    assert_eq!(
+      nearest_offset_in_direction(&offsets, &Direction::Up),
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn wraps_to_farthest_in_opposite_direction() {
+    let offsets = [Point { x: -100, y: 0 }, Point { x: -300, y: 0 }];
+
+    assert_eq!(
+      nearest_offset_in_direction(&offsets, &Direction::Right),
+      Some(1)
+    );
+  }
+
+  #[test]
+  fn falls_back_to_top_most_floating_window() {
+    let origin_workspace = workspace(TilingDirection::Horizontal);
+    let origin_window = tiling_window(1);
+    attach(origin_window.clone(), origin_workspace.clone());
+
+    let state = wm_state(&origin_workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    // Workspace to the right with only floating windows.
+    let target_monitor =
+      monitor(1, Rect::from_xy(1920, 0, 1920, 1080), false);
+    let target_workspace = workspace(TilingDirection::Horizontal);
+    let floating_windows = [2, 3].map(|handle| {
+      non_tiling_window(
+        handle,
+        WindowState::Floating(FloatingStateConfig::default()),
+      )
+    });
+
+    attach(target_monitor.clone(), state.root_container.clone());
+    attach(target_workspace.clone(), target_monitor);
+
+    for window in &floating_windows {
+      attach(window.clone(), target_workspace.clone());
+    }
+
+    for window in &floating_windows {
+      set_focused_descendant(&window.clone().into(), None);
+
+      let focus_target = workspace_focus_target(
+        &origin_window.clone().into(),
+        &Direction::Right,
+        &state,
+        &config,
+      )
+      .unwrap();
+
+      assert_eq!(
+        focus_target.map(|target| target.id()),
+        Some(window.id())
+      );
+    }
+  }
+}
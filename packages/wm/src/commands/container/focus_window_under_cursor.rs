@@ -0,0 +1,36 @@
+use tracing::info;
+use wm_platform::Platform;
+
+use super::set_focused_descendant;
+use crate::{traits::CommonGetters, wm_state::WmState};
+
+/// Focuses the top-most window under the cursor.
+///
+/// Overlapping windows (e.g. a floating window stacked over a tiling
+/// window) are resolved by their stacking order. Does nothing if the
+/// cursor isn't over a window.
+pub fn focus_window_under_cursor(
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let cursor_position = Platform::mouse_position()?;
+
+  let Some(window) = state.window_at_point(&cursor_position) else {
+    return Ok(());
+  };
+
+  if state
+    .focused_container()
+    .is_some_and(|focused| focused.id() == window.id())
+  {
+    return Ok(());
+  }
+
+  info!("Focusing window under cursor: {window}");
+
+  // The cursor is already over the window, so there's no need for a
+  // cursor jump.
+  set_focused_descendant(&window.into(), None);
+  state.pending_sync.queue_focus_change();
+
+  Ok(())
+}
@@ -1,25 +1,41 @@
 mod attach_container;
+mod balance_container;
 mod detach_container;
+mod equalize_axis;
+mod equalize_tiling_children;
 mod flatten_child_split_containers;
+mod flatten_redundant_split_containers;
 mod flatten_split_container;
 mod focus_container_by_id;
+mod focus_floating;
 mod focus_in_direction;
+mod focus_window_under_cursor;
 mod move_container_within_tree;
 mod replace_container;
 mod resize_tiling_container;
 mod set_focused_descendant;
+mod swap_containers;
 mod toggle_tiling_direction;
+mod transpose_layout;
 mod wrap_in_split_container;
 
 pub use attach_container::*;
+pub use balance_container::*;
 pub use detach_container::*;
+pub use equalize_axis::*;
+pub use equalize_tiling_children::*;
 pub use flatten_child_split_containers::*;
+pub use flatten_redundant_split_containers::*;
 pub use flatten_split_container::*;
 pub use focus_container_by_id::*;
+pub use focus_floating::*;
 pub use focus_in_direction::*;
+pub use focus_window_under_cursor::*;
 pub use move_container_within_tree::*;
 pub use replace_container::*;
 pub use resize_tiling_container::*;
 pub use set_focused_descendant::*;
+pub use swap_containers::*;
 pub use toggle_tiling_direction::*;
+pub use transpose_layout::*;
 pub use wrap_in_split_container::*;
@@ -45,4 +45,83 @@ pub fn resize_tiling_container(
 
     sibling.set_tiling_size(sibling.tiling_size() - size_delta);
   }
+
+  normalize_tiling_sizes(container_to_resize);
+}
+
+/// Corrects floating-point drift in the tiling sizes of the container and
+/// its siblings, such that they sum to exactly 1.
+///
+/// The rounding error is added to the last tiling child, so that repeated
+/// resizes don't accumulate into a visible gap or overlap.
+fn normalize_tiling_sizes(container: &TilingContainer) {
+  let Some(parent) = container.parent() else {
+    return;
+  };
+
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+
+  let mut tiling_sizes = tiling_children
+    .iter()
+    .map(TilingSizeGetters::tiling_size)
+    .collect::<Vec<_>>();
+
+  normalize_sizes(&mut tiling_sizes);
+
+  for (child, tiling_size) in tiling_children.iter().zip(tiling_sizes) {
+    child.set_tiling_size(tiling_size);
+  }
+}
+
+/// Adds the rounding error of the sizes to the last size, such that they
+/// sum to 1.
+fn normalize_sizes(sizes: &mut [f32]) {
+  let total_size = sizes.iter().sum::<f32>();
+
+  if let Some(last_size) = sizes.last_mut() {
+    *last_size += 1. - total_size;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::test_utils::{attach, tiling_window, workspace};
+
+  #[test]
+  fn normalizes_sizes_to_one() {
+    let mut sizes = [0.3, 0.3, 0.3];
+    normalize_sizes(&mut sizes);
+
+    assert!((sizes.iter().sum::<f32>() - 1.).abs() < f32::EPSILON);
+    assert!((sizes[2] - 0.4).abs() < f32::EPSILON);
+  }
+
+  #[test]
+  fn sizes_sum_to_one_after_repeated_resizes() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let windows = (1..=3).map(tiling_window).collect::<Vec<_>>();
+
+    for window in &windows {
+      attach(window.clone(), workspace.clone());
+    }
+
+    for step in 0..100 {
+      let window = &windows[step % windows.len()];
+
+      #[allow(clippy::cast_precision_loss)]
+      let target_size = 0.1 + (step % 7) as f32 * 0.1;
+
+      resize_tiling_container(&window.clone().into(), target_size);
+    }
+
+    let total_size = workspace
+      .tiling_children()
+      .map(|child| child.tiling_size())
+      .sum::<f32>();
+
+    assert!((total_size - 1.).abs() < 1e-6);
+  }
 }
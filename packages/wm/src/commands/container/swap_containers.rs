@@ -0,0 +1,176 @@
+use anyhow::{bail, Context};
+
+use crate::{
+  models::TilingContainer,
+  traits::{CommonGetters, TilingSizeGetters},
+  wm_state::WmState,
+};
+
+/// Swaps the positions of two tiling containers in the tree.
+///
+/// The containers can have different parents and be at different depths.
+/// Tiling sizes stay with the positions rather than the containers, so
+/// the layout is otherwise unchanged. No split containers are created or
+/// flattened.
+pub fn swap_containers(
+  container_a: &TilingContainer,
+  container_b: &TilingContainer,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if container_a
+    .self_and_ancestors()
+    .any(|c| c.id() == container_b.id())
+    || container_b
+      .self_and_ancestors()
+      .any(|c| c.id() == container_a.id())
+  {
+    bail!("Cannot swap a container with itself or its ancestor.");
+  }
+
+  let parent_a = container_a.parent().context("No parent.")?;
+  let parent_b = container_b.parent().context("No parent.")?;
+  let index_a = container_a.index();
+  let index_b = container_b.index();
+  let tiling_size_a = container_a.tiling_size();
+  let tiling_size_b = container_b.tiling_size();
+
+  if parent_a == parent_b {
+    parent_a.borrow_children_mut().swap(index_a, index_b);
+  } else {
+    parent_a.borrow_children_mut()[index_a] = container_b.clone().into();
+    parent_b.borrow_children_mut()[index_b] = container_a.clone().into();
+
+    // Swap the IDs in each parent's focus order.
+    for (parent, old_id, new_id) in [
+      (&parent_a, container_a.id(), container_b.id()),
+      (&parent_b, container_b.id(), container_a.id()),
+    ] {
+      for id in parent.borrow_child_focus_order_mut().iter_mut() {
+        if *id == old_id {
+          *id = new_id;
+        }
+      }
+    }
+
+    *container_a.borrow_parent_mut() = Some(parent_b.clone());
+    *container_b.borrow_parent_mut() = Some(parent_a.clone());
+  }
+
+  container_a.set_tiling_size(tiling_size_b);
+  container_b.set_tiling_size(tiling_size_a);
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(parent_a)
+    .queue_container_to_redraw(parent_b);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use uuid::Uuid;
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::{
+    models::Container,
+    test_utils::{attach, split, tiling_window, wm_state, workspace},
+  };
+
+  fn focus_order(container: &Container) -> Vec<Uuid> {
+    container
+      .borrow_child_focus_order()
+      .iter()
+      .copied()
+      .collect()
+  }
+
+  #[test]
+  fn swaps_across_splits_at_different_depths() {
+    // H[1 V[2 H[3 4]]]
+    let workspace = workspace(TilingDirection::Horizontal);
+    let vertical_split = split(TilingDirection::Vertical);
+    let horizontal_split = split(TilingDirection::Horizontal);
+    let windows = (1..=4).map(tiling_window).collect::<Vec<_>>();
+
+    attach(windows[0].clone(), workspace.clone());
+    attach(vertical_split.clone(), workspace.clone());
+    attach(windows[1].clone(), vertical_split.clone());
+    attach(horizontal_split.clone(), vertical_split.clone());
+    attach(windows[2].clone(), horizontal_split.clone());
+    attach(windows[3].clone(), horizontal_split.clone());
+
+    windows[0].set_tiling_size(0.7);
+    vertical_split.set_tiling_size(0.3);
+    windows[2].set_tiling_size(0.2);
+    windows[3].set_tiling_size(0.8);
+
+    let mut state = wm_state(&workspace);
+
+    swap_containers(
+      &windows[0].clone().into(),
+      &windows[2].clone().into(),
+      &mut state,
+    )
+    .unwrap();
+
+    let workspace: Container = workspace.into();
+    let horizontal_split: Container = horizontal_split.into();
+
+    assert_eq!(windows[0].parent(), Some(horizontal_split.clone()));
+    assert_eq!(windows[0].index(), 0);
+    assert_eq!(windows[2].parent(), Some(workspace.clone()));
+    assert_eq!(windows[2].index(), 0);
+
+    assert_eq!(
+      focus_order(&workspace),
+      [windows[2].id(), vertical_split.id()]
+    );
+    assert_eq!(
+      focus_order(&horizontal_split),
+      [windows[0].id(), windows[3].id()]
+    );
+
+    // Sizes stay with the positions.
+    assert!((windows[2].tiling_size() - 0.7).abs() < f32::EPSILON);
+    assert!((windows[0].tiling_size() - 0.2).abs() < f32::EPSILON);
+    assert!((vertical_split.tiling_size() - 0.3).abs() < f32::EPSILON);
+    assert!((windows[3].tiling_size() - 0.8).abs() < f32::EPSILON);
+  }
+
+  #[test]
+  fn swaps_siblings_with_different_sizes() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let windows = (1..=2).map(tiling_window).collect::<Vec<_>>();
+
+    for window in &windows {
+      attach(window.clone(), workspace.clone());
+    }
+
+    windows[0].set_tiling_size(0.7);
+    windows[1].set_tiling_size(0.3);
+
+    let mut state = wm_state(&workspace);
+    let focus_order_before = focus_order(&workspace.clone().into());
+
+    swap_containers(
+      &windows[0].clone().into(),
+      &windows[1].clone().into(),
+      &mut state,
+    )
+    .unwrap();
+
+    assert_eq!(windows[1].index(), 0);
+    assert_eq!(windows[0].index(), 1);
+
+    for window in &windows {
+      assert_eq!(window.parent(), Some(workspace.clone().into()));
+    }
+
+    assert_eq!(focus_order(&workspace.into()), focus_order_before);
+
+    assert!((windows[1].tiling_size() - 0.7).abs() < f32::EPSILON);
+    assert!((windows[0].tiling_size() - 0.3).abs() < f32::EPSILON);
+  }
+}
@@ -0,0 +1,109 @@
+use anyhow::Context;
+use wm_common::WmEvent;
+
+use crate::{
+  models::{Container, DirectionContainer, Workspace},
+  traits::{CommonGetters, TilingDirectionGetters},
+  wm_state::WmState,
+};
+
+/// Transposes the layout of the container's workspace, such that rows
+/// become columns and vice versa.
+///
+/// This inverts the tiling direction of the workspace and all of its
+/// split containers. Window membership and tiling sizes are kept, so for
+/// example a grid of 2 rows by 3 columns becomes 3 rows by 2 columns.
+pub fn transpose_layout(
+  container: &Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let workspace = container.workspace().context("No workspace.")?;
+
+  for direction_container in &invert_tiling_directions(&workspace) {
+    state.emit_event(WmEvent::TilingDirectionChanged {
+      direction_container: direction_container.to_dto()?,
+      new_tiling_direction: direction_container.tiling_direction(),
+    });
+  }
+
+  state.pending_sync.queue_container_to_redraw(workspace);
+
+  Ok(())
+}
+
+/// Inverts the tiling direction of the workspace and all of its split
+/// containers.
+///
+/// Returns the direction containers that were changed.
+fn invert_tiling_directions(
+  workspace: &Workspace,
+) -> Vec<DirectionContainer> {
+  let direction_containers = workspace
+    .self_and_descendants()
+    .filter_map(|descendant| descendant.as_direction_container().ok())
+    .collect::<Vec<_>>();
+
+  for direction_container in &direction_containers {
+    direction_container.set_tiling_direction(
+      direction_container.tiling_direction().inverse(),
+    );
+  }
+
+  direction_containers
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::{
+    models::TilingWindow,
+    test_utils::{attach, split, tiling_window, workspace},
+  };
+
+  /// Gets the (row, column) of a window in a grid of nested splits.
+  fn grid_cell(
+    window: &TilingWindow,
+    workspace: &Workspace,
+  ) -> (usize, usize) {
+    let outer_index = window.parent().expect("No parent.").index();
+    let inner_index = window.index();
+
+    match workspace.tiling_direction() {
+      TilingDirection::Vertical => (outer_index, inner_index),
+      TilingDirection::Horizontal => (inner_index, outer_index),
+    }
+  }
+
+  #[test]
+  fn transposes_grid() {
+    // 2 rows by 3 columns.
+    let workspace = workspace(TilingDirection::Vertical);
+    let mut windows = Vec::new();
+
+    for row in 0..2 {
+      let row_split = split(TilingDirection::Horizontal);
+      attach(row_split.clone(), workspace.clone());
+
+      for column in 0..3 {
+        let window = tiling_window(row * 3 + column);
+        attach(window.clone(), row_split.clone());
+        windows.push(window);
+      }
+    }
+
+    let cells_before = windows
+      .iter()
+      .map(|window| grid_cell(window, &workspace))
+      .collect::<Vec<_>>();
+
+    assert_eq!(invert_tiling_directions(&workspace).len(), 3);
+    assert_eq!(workspace.tiling_direction(), TilingDirection::Horizontal);
+
+    // 3 rows by 2 columns, with each window's row and column swapped.
+    for (window, (row, column)) in windows.iter().zip(cells_before) {
+      assert_eq!(grid_cell(window, &workspace), (column, row));
+    }
+  }
+}
@@ -5,6 +5,7 @@ mod platform_sync;
 mod reload_config;
 mod shell_exec;
 mod toggle_pause;
+mod undo_layout;
 
 pub use cycle_focus::*;
 pub use disable_binding_mode::*;
@@ -13,3 +14,4 @@ pub use platform_sync::*;
 pub use reload_config::*;
 pub use shell_exec::*;
 pub use toggle_pause::*;
+pub use undo_layout::*;
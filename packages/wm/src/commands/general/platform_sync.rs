@@ -2,14 +2,16 @@ use std::time::Duration;
 
 use anyhow::Context;
 use tokio::task;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 use wm_common::{
-  CornerStyle, CursorJumpTrigger, DisplayState, HideMethod, OpacityValue,
-  UniqueExt, WindowEffectConfig, WindowState, WmEvent,
+  CornerStyle, CursorJumpAnchor, CursorJumpConfig, CursorJumpTrigger,
+  DisplayState, HideMethod, OpacityValue, Point, UniqueExt,
+  WindowEffectConfig, WindowState, WmEvent,
 };
 use wm_platform::{Platform, ZOrder};
 
 use crate::{
+  commands::window::{move_sticky_windows, unpeek_window},
   models::{Container, WindowContainer},
   traits::{CommonGetters, PositionGetters, WindowGetters},
   user_config::UserConfig,
@@ -23,6 +25,15 @@ pub fn platform_sync(
   let focused_container =
     state.focused_container().context("No focused container.")?;
 
+  // Revert the peek once focus moves away from the peeked window.
+  if state
+    .peeked_window
+    .as_ref()
+    .is_some_and(|peeked| peeked.id() != focused_container.id())
+  {
+    unpeek_window(state);
+  }
+
   if state.pending_sync.needs_focus_update() {
     sync_focus(&focused_container, state)?;
   }
@@ -30,13 +41,31 @@ pub fn platform_sync(
   if !state.pending_sync.containers_to_redraw().is_empty()
     || !state.pending_sync.workspaces_to_reorder().is_empty()
   {
+    // The displayed workspace of a monitor can change without going
+    // through `focus_workspace` (e.g. when a workspace is moved to
+    // another monitor).
+    move_sticky_windows(state)?;
+
     redraw_containers(&focused_container, state, config)?;
   }
 
+  // Avoid moving the cursor while a window is being dragged.
+  let is_dragging = state
+    .windows()
+    .iter()
+    .any(|window| window.active_drag().is_some());
+
+  let cursor_jump = state
+    .pending_sync
+    .cursor_jump_override()
+    .unwrap_or(&config.value.general.cursor_jump)
+    .clone();
+
   if state.pending_sync.needs_cursor_jump()
-    && config.value.general.cursor_jump.enabled
+    && cursor_jump.enabled
+    && !is_dragging
   {
-    jump_cursor(focused_container.clone(), state, config)?;
+    jump_cursor(focused_container.clone(), &cursor_jump, state)?;
   }
 
   if state.pending_sync.needs_focused_effect_update()
@@ -171,7 +200,21 @@ fn redraw_containers(
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<()> {
+  debug!(
+    "Redrawing {} queued containers.",
+    state.pending_sync.queued_len()
+  );
+
   let windows_to_redraw = state.windows_to_redraw();
+
+  // Redrawing the peeked window moves it back to its regular position.
+  if state
+    .peeked_window
+    .as_ref()
+    .is_some_and(|peeked| windows_to_redraw.contains(peeked))
+  {
+    state.peeked_window = None;
+  }
   let windows_to_bring_to_front =
     windows_to_bring_to_front(focused_container, state)?;
 
@@ -257,9 +300,14 @@ fn redraw_containers(
       },
     );
 
-    let rect = window
-      .to_rect()?
-      .apply_delta(&window.total_border_delta()?, None);
+    let rect = match (window.state(), window.aspect_ratio_lock()) {
+      // Shrink aspect ratio locked windows to fit within their tile.
+      (WindowState::Tiling, Some(aspect_ratio)) => {
+        window.to_rect()?.fit_aspect_ratio(aspect_ratio)
+      }
+      _ => window.to_rect()?,
+    }
+    .apply_delta(&window.total_border_delta()?, None);
 
     let is_visible = matches!(
       window.display_state(),
@@ -321,11 +369,9 @@ fn redraw_containers(
 
 fn jump_cursor(
   focused_container: Container,
+  cursor_jump: &CursorJumpConfig,
   state: &WmState,
-  config: &UserConfig,
 ) -> anyhow::Result<()> {
-  let cursor_jump = &config.value.general.cursor_jump;
-
   let jump_target = match cursor_jump.trigger {
     CursorJumpTrigger::WindowFocus => Some(focused_container),
     CursorJumpTrigger::MonitorFocus => {
@@ -344,9 +390,17 @@ fn jump_cursor(
   };
 
   if let Some(jump_target) = jump_target {
-    let center = jump_target.to_rect()?.center_point();
+    let target_rect = jump_target.to_rect()?;
+
+    let position = match cursor_jump.anchor {
+      CursorJumpAnchor::Center => target_rect.center_point(),
+      CursorJumpAnchor::TopLeft => Point {
+        x: target_rect.left,
+        y: target_rect.top,
+      },
+    };
 
-    if let Err(err) = Platform::set_cursor_pos(center.x, center.y) {
+    if let Err(err) = Platform::set_cursor_pos(position.x, position.y) {
       warn!("Failed to set cursor position: {}", err);
     }
   }
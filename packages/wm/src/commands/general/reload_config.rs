@@ -25,6 +25,13 @@ pub fn reload_config(
   // Re-run window rules on all active windows.
   for window in state.windows() {
     window.set_done_window_rules(Vec::new());
+
+    // The window's original size isn't known anymore, so an aspect ratio
+    // lock can only be removed on reload.
+    if !config.has_aspect_ratio_rule(&window)? {
+      window.set_aspect_ratio_lock(None);
+    }
+
     run_window_rules(window, &WindowRuleEvent::Manage, state, config)?;
   }
 
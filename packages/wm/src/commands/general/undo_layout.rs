@@ -0,0 +1,191 @@
+use anyhow::Context;
+use tracing::info;
+
+use crate::{
+  commands::{
+    container::{
+      flatten_redundant_split_containers, move_container_within_tree,
+    },
+    window::update_window_state,
+  },
+  models::WindowSlot,
+  traits::{CommonGetters, TilingSizeGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Reverts the most recent layout change (e.g. a tiling window being
+/// dragged and dropped) by moving the window back to its prior slot.
+pub fn undo_layout(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(slot) = state.layout_history.pop_undo() else {
+    info!("No layout change to undo.");
+    return Ok(());
+  };
+
+  if let Some(current_slot) = restore_window_slot(&slot, state, config)? {
+    state.layout_history.push_redo(current_slot);
+  }
+
+  Ok(())
+}
+
+/// Reapplies the most recently undone layout change.
+pub fn redo_layout(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(slot) = state.layout_history.pop_redo() else {
+    info!("No layout change to redo.");
+    return Ok(());
+  };
+
+  if let Some(current_slot) = restore_window_slot(&slot, state, config)? {
+    state
+      .layout_history
+      .push_undo(current_slot, config.value.general.undo_limit);
+  }
+
+  Ok(())
+}
+
+/// Moves the window back to the given slot and restores the tiling sizes
+/// of its siblings.
+///
+/// The slot's parent might no longer exist (e.g. a split container that
+/// has since been flattened), in which case the window is placed beside a
+/// former sibling instead.
+///
+/// Returns the window's slot from before it was restored, or `None` if
+/// the window no longer exists.
+fn restore_window_slot(
+  slot: &WindowSlot,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<Option<WindowSlot>> {
+  let Some(window) = state
+    .container_by_id(slot.window_id)
+    .and_then(|container| container.as_window_container().ok())
+  else {
+    return Ok(None);
+  };
+
+  info!("Restoring window to previous layout slot: {window}");
+
+  let current_slot =
+    window.as_tiling_window().and_then(WindowSlot::from_window);
+  let origin_workspace = window.workspace().context("No workspace.")?;
+
+  let window =
+    update_window_state(window, slot.state.clone(), state, config)?;
+
+  let target_parent = state
+    .container_by_id(slot.parent_id)
+    .or_else(|| {
+      slot
+        .tiling_sizes
+        .iter()
+        .filter(|(id, _)| *id != slot.window_id)
+        .find_map(|(id, _)| state.container_by_id(*id)?.parent())
+    })
+    .or_else(|| window.workspace().map(Into::into))
+    .context("No parent.")?;
+
+  move_container_within_tree(
+    &window.clone().into(),
+    &target_parent,
+    slot.index.min(target_parent.child_count()),
+    state,
+  )?;
+
+  // Clean up any split containers that were created by the change (e.g.
+  // when the window was dropped on the edge of another window).
+  flatten_redundant_split_containers(&origin_workspace.clone().into())?;
+
+  let target_workspace = window.workspace().context("No workspace.")?;
+
+  if target_workspace.id() != origin_workspace.id() {
+    flatten_redundant_split_containers(&target_workspace.clone().into())?;
+  }
+
+  for (id, tiling_size) in &slot.tiling_sizes {
+    let sibling = state
+      .container_by_id(*id)
+      .and_then(|container| container.as_tiling_container().ok())
+      .filter(|sibling| {
+        sibling
+          .parent()
+          .is_some_and(|parent| parent.id() == target_parent.id())
+      });
+
+    if let Some(sibling) = sibling {
+      sibling.set_tiling_size(*tiling_size);
+    }
+  }
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(origin_workspace)
+    .queue_container_to_redraw(target_workspace);
+
+  Ok(current_slot)
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{ParsedConfig, TilingDirection};
+
+  use super::*;
+  use crate::{
+    commands::container::wrap_in_split_container,
+    models::Container,
+    test_utils::{attach, split, tiling_window, wm_state, workspace},
+  };
+
+  #[test]
+  fn undoes_split_creating_drop() {
+    // H[1 2] -> H[V[1 2]] (2 dropped below 1) -> undo -> H[1 2]
+    let workspace = workspace(TilingDirection::Horizontal);
+    let window_1 = tiling_window(1);
+    let window_2 = tiling_window(2);
+
+    attach(window_1.clone(), workspace.clone());
+    attach(window_2.clone(), workspace.clone());
+
+    let mut state = wm_state(&workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+    let slot = WindowSlot::from_window(&window_2).expect("No slot.");
+
+    let split_container = split(TilingDirection::Vertical);
+    wrap_in_split_container(
+      &split_container,
+      &workspace.clone().into(),
+      &[window_1.clone().into()],
+    )
+    .unwrap();
+    move_container_within_tree(
+      &window_2.clone().into(),
+      &split_container.clone().into(),
+      1,
+      &state,
+    )
+    .unwrap();
+
+    assert!(workspace.children().iter().all(Container::is_split));
+
+    state.layout_history.push(slot, 10);
+    undo_layout(&mut state, &config).unwrap();
+
+    let children = workspace.children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].id(), window_1.id());
+    assert_eq!(children[1].id(), window_2.id());
+    assert!((window_1.tiling_size() - 0.5).abs() < f32::EPSILON);
+    assert!((window_2.tiling_size() - 0.5).abs() < f32::EPSILON);
+
+    // The undone change can be redone.
+    assert!(state.layout_history.pop_redo().is_some());
+  }
+}
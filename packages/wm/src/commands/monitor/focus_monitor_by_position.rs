@@ -0,0 +1,89 @@
+use anyhow::Context;
+use wm_common::Point;
+
+use crate::{
+  commands::workspace::focus_workspace,
+  models::{Workspace, WorkspaceTarget},
+  traits::CommonGetters,
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Focuses the monitor that contains the given point in screen space.
+///
+/// Falls back to the monitor nearest to the point if it's outside of all
+/// monitors.
+pub fn focus_monitor_by_position(
+  point: &Point,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(workspace) = workspace_to_focus(point, state)? else {
+    return Ok(());
+  };
+
+  focus_workspace(
+    WorkspaceTarget::Name(workspace.config().name),
+    state,
+    config,
+  )
+}
+
+/// Gets the displayed workspace of the monitor at the given point, or
+/// `None` if it's already focused.
+///
+/// Focusing an already focused workspace by name would otherwise toggle
+/// to the most recent workspace (if `toggle_workspace_on_refocus` is
+/// enabled).
+fn workspace_to_focus(
+  point: &Point,
+  state: &WmState,
+) -> anyhow::Result<Option<Workspace>> {
+  let workspace = state
+    .nearest_monitor_to_point(point)
+    .context("No monitor found.")?
+    .displayed_workspace()
+    .context("Failed to get target workspace.")?;
+
+  let is_focused = state
+    .focused_container()
+    .and_then(|focused| focused.workspace())
+    .is_some_and(|focused_workspace| {
+      focused_workspace.id() == workspace.id()
+    });
+
+  Ok((!is_focused).then_some(workspace))
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::test_utils::{attach, monitor, wm_state, workspace};
+
+  #[test]
+  fn skips_already_focused_workspace() {
+    let focused_workspace = workspace(TilingDirection::Horizontal);
+    let state = wm_state(&focused_workspace);
+
+    // A second monitor is attached to the right of the focused one.
+    let other_monitor =
+      monitor(1, Rect::from_xy(1920, 0, 1920, 1080), false);
+    let other_workspace = workspace(TilingDirection::Horizontal);
+    attach(other_monitor.clone(), state.root_container.clone());
+    attach(other_workspace.clone(), other_monitor);
+
+    let workspace_at = |x, y| {
+      workspace_to_focus(&Point { x, y }, &state)
+        .unwrap()
+        .map(|workspace| workspace.id())
+    };
+
+    assert_eq!(workspace_at(500, 500), None);
+    assert_eq!(workspace_at(2500, 500), Some(other_workspace.id()));
+
+    // Points outside of all monitors use the nearest monitor.
+    assert_eq!(workspace_at(5000, 500), Some(other_workspace.id()));
+  }
+}
@@ -1,11 +1,13 @@
 mod add_monitor;
 mod focus_monitor;
+mod focus_monitor_by_position;
 mod remove_monitor;
 mod sort_monitors;
 mod update_monitor;
 
 pub use add_monitor::*;
 pub use focus_monitor::*;
+pub use focus_monitor_by_position::*;
 pub use remove_monitor::*;
 pub use sort_monitors::*;
 pub use update_monitor::*;
@@ -0,0 +1,164 @@
+use anyhow::Context;
+use tracing::info;
+use wm_common::{FloatingPlacementConfig, Rect, WindowState};
+
+use crate::{
+  models::WindowContainer,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Applies the floating placement of the first matching window rule (if
+/// any) to a floating window.
+///
+/// The placement is only applied the first time the window floats,
+/// unless the rule has `reapply` set. Windows that are temporarily
+/// floating while being dragged are skipped.
+pub fn apply_floating_placement(
+  window: &WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !matches!(window.state(), WindowState::Floating(_))
+    || window.active_drag().is_some()
+  {
+    return Ok(());
+  }
+
+  let Some(placement) = config.floating_placement_rule(window)? else {
+    return Ok(());
+  };
+
+  let is_first_float = state.floating_placed_windows.insert(window.id());
+
+  if !is_first_float && !placement.reapply {
+    return Ok(());
+  }
+
+  info!("Applying floating placement from window rule: {window}");
+
+  let monitor_rect = window.monitor().context("No monitor.")?.to_rect()?;
+  let workspace_rect =
+    window.workspace().context("No workspace.")?.to_rect()?;
+
+  window.set_floating_placement(resolve_placement(
+    placement,
+    &window.floating_placement(),
+    &monitor_rect,
+    &workspace_rect,
+  ));
+
+  // Centered placements aren't marked as custom, same as when positioned
+  // via `position --centered`.
+  if placement.centered
+    || placement.x_pos.is_some()
+    || placement.y_pos.is_some()
+  {
+    window.set_has_custom_floating_placement(!placement.centered);
+  }
+
+  state.pending_sync.queue_container_to_redraw(window.clone());
+
+  Ok(())
+}
+
+/// Gets the floating placement of a window after applying the placement
+/// config to its current placement.
+///
+/// Percentage lengths are relative to the monitor, and centered windows
+/// are centered within the workspace.
+fn resolve_placement(
+  placement: &FloatingPlacementConfig,
+  current_placement: &Rect,
+  monitor_rect: &Rect,
+  workspace_rect: &Rect,
+) -> Rect {
+  let width = placement
+    .width
+    .as_ref()
+    .map_or(current_placement.width(), |width| {
+      width.to_px(monitor_rect.width(), None)
+    });
+
+  let height = placement
+    .height
+    .as_ref()
+    .map_or(current_placement.height(), |height| {
+      height.to_px(monitor_rect.height(), None)
+    });
+
+  let resized = Rect::from_xy(
+    current_placement.x(),
+    current_placement.y(),
+    width,
+    height,
+  );
+
+  if placement.centered {
+    resized.translate_to_center(workspace_rect)
+  } else {
+    Rect::from_xy(
+      placement.x_pos.unwrap_or(resized.x()),
+      placement.y_pos.unwrap_or(resized.y()),
+      width,
+      height,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use wm_common::LengthValue;
+
+  use super::*;
+
+  /// A non-primary monitor to the right of a 1920x1080 primary monitor.
+  fn secondary_monitor_rect() -> Rect {
+    Rect::from_xy(1920, 0, 2560, 1440)
+  }
+
+  #[test]
+  fn centers_within_workspace() {
+    let placement = FloatingPlacementConfig {
+      centered: true,
+      width: Some(LengthValue::from_px(800)),
+      height: Some(LengthValue::from_px(600)),
+      ..FloatingPlacementConfig::default()
+    };
+
+    // Workspace rect is inset by the gaps and a 40px taskbar.
+    let workspace_rect = Rect::from_xy(1930, 10, 2540, 1380);
+
+    let resolved = resolve_placement(
+      &placement,
+      &Rect::from_xy(100, 100, 300, 200),
+      &secondary_monitor_rect(),
+      &workspace_rect,
+    );
+
+    assert_eq!(resolved, Rect::from_xy(2800, 400, 800, 600));
+  }
+
+  #[test]
+  fn resolves_percentages_against_monitor() {
+    let placement = FloatingPlacementConfig {
+      x_pos: Some(2000),
+      width: Some(LengthValue::from_str("50%").unwrap()),
+      height: Some(LengthValue::from_str("25%").unwrap()),
+      ..FloatingPlacementConfig::default()
+    };
+
+    let resolved = resolve_placement(
+      &placement,
+      &Rect::from_xy(2100, 300, 300, 200),
+      &secondary_monitor_rect(),
+      &Rect::from_xy(1920, 0, 2560, 1400),
+    );
+
+    // Keeps the current y-position, since only `x_pos` is set.
+    assert_eq!(resolved, Rect::from_xy(2000, 300, 1280, 360));
+  }
+}
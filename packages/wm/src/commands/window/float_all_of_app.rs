@@ -0,0 +1,138 @@
+use tracing::info;
+use wm_common::{FloatingStateConfig, Rect, WindowState};
+
+use super::update_window_state;
+use crate::{
+  models::WindowContainer,
+  traits::{PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Converts all tiling windows of the given application (across all
+/// workspaces) to floating.
+///
+/// Each window floats in place, keeping the rect it had while tiling.
+pub fn float_all_of_app(
+  process_name: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  // Rects are retrieved upfront, since floating a window resizes its
+  // tiling siblings.
+  let app_windows = app_windows(process_name, state)
+    .into_iter()
+    .filter(WindowContainer::is_tiling_window)
+    .map(|window| anyhow::Ok((window.to_rect()?, window)))
+    .try_collect::<Vec<_>>()?;
+
+  info!(
+    "Floating {} windows of application: {process_name}",
+    app_windows.len()
+  );
+
+  for (rect, window) in app_windows {
+    float_in_place(window, rect, state, config)?;
+  }
+
+  Ok(())
+}
+
+/// Converts the window to floating, using the given rect as its floating
+/// placement.
+fn float_in_place(
+  window: WindowContainer,
+  rect: Rect,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<WindowContainer> {
+  let window = update_window_state(
+    window,
+    WindowState::Floating(FloatingStateConfig {
+      centered: false,
+      ..config.value.window_behavior.state_defaults.floating
+    }),
+    state,
+    config,
+  )?;
+
+  window.set_floating_placement(rect);
+  window.set_has_custom_floating_placement(true);
+
+  Ok(window)
+}
+
+/// Gets all managed windows that belong to the given application.
+///
+/// Process names are compared case-insensitively.
+fn app_windows(
+  process_name: &str,
+  state: &WmState,
+) -> Vec<WindowContainer> {
+  state
+    .windows()
+    .into_iter()
+    .filter(|window| {
+      window
+        .native()
+        .process_name()
+        .is_ok_and(|name| name.eq_ignore_ascii_case(process_name))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{ParsedConfig, TilingDirection};
+
+  use super::*;
+  use crate::{
+    test_utils::{app_tiling_window, attach, wm_state, workspace},
+    traits::{CommonGetters, TilingSizeGetters},
+  };
+
+  #[test]
+  fn floats_app_windows_in_place() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let app_windows = (1..=3)
+      .map(|handle| app_tiling_window(handle, "app.exe"))
+      .collect::<Vec<_>>();
+    let other_window = app_tiling_window(4, "other.exe");
+
+    attach(app_windows[0].clone(), workspace.clone());
+    attach(other_window.clone(), workspace.clone());
+    attach(app_windows[1].clone(), workspace.clone());
+    attach(app_windows[2].clone(), workspace.clone());
+
+    let mut state = wm_state(&workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    let prev_rects = app_windows
+      .iter()
+      .map(|window| window.to_rect().unwrap())
+      .collect::<Vec<_>>();
+
+    // Process names are matched case-insensitively.
+    float_all_of_app("APP.exe", &mut state, &config).unwrap();
+
+    for (window, prev_rect) in app_windows.iter().zip(prev_rects) {
+      let window = state
+        .window_from_native(&window.native())
+        .expect("No window.");
+
+      assert!(matches!(window.state(), WindowState::Floating(_)));
+      assert_eq!(window.floating_placement(), prev_rect);
+      assert!(window.has_custom_floating_placement());
+    }
+
+    assert_eq!(other_window.state(), WindowState::Tiling);
+    assert_eq!(
+      workspace
+        .tiling_children()
+        .map(|child| child.id())
+        .collect::<Vec<_>>(),
+      vec![other_window.id()]
+    );
+    assert!((other_window.tiling_size() - 1.).abs() < 1e-5);
+  }
+}
@@ -0,0 +1,307 @@
+use anyhow::Context;
+use tracing::{info, warn};
+use wm_common::{
+  Direction, DragResult, DropPosition, FloatingStateConfig, Point, Rect,
+  TilingDirection, WindowState,
+};
+use wm_platform::Platform;
+
+use super::update_window_state;
+use crate::{
+  commands::container::flatten_split_container,
+  events::{drop_as_tiling_window, drop_position},
+  models::{KeyboardGrab, TilingWindow, WindowContainer, WindowSlot},
+  traits::{CommonGetters, PositionGetters, TilingDirectionGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Grabs the tiling window so that it can be placed with the keyboard.
+/// Calling this again on the same window cancels the grab.
+///
+/// The drop anchor starts at the window's center point. The cursor is
+/// moved along with the anchor to indicate where the window will be
+/// dropped.
+pub fn grab_window(
+  window: &TilingWindow,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let was_grabbed = state
+    .keyboard_grab
+    .as_ref()
+    .is_some_and(|grab| grab.window.id() == window.id());
+
+  // Only a single window can be grabbed at a time.
+  cancel_grab(state);
+
+  if was_grabbed {
+    return Ok(());
+  }
+
+  let window = WindowContainer::from(window.clone());
+  info!("Grabbing window: {window}");
+
+  let cursor_position = Platform::mouse_position().ok();
+  let anchor = window.to_rect()?.center_point();
+  set_cursor_position(&anchor);
+
+  state.keyboard_grab = Some(KeyboardGrab {
+    window,
+    anchor,
+    cursor_position,
+  });
+
+  Ok(())
+}
+
+/// Moves the drop anchor of the grabbed window in the given direction.
+///
+/// The anchor steps through the drop zones of the container under it
+/// (i.e. its edges and center), and then on to the edge of the adjacent
+/// container in the given direction.
+pub fn move_grab_anchor(
+  direction: &Direction,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(grab) = state.keyboard_grab.clone() else {
+    return Ok(());
+  };
+
+  if grab.window.is_detached() {
+    cancel_grab(state);
+    return Ok(());
+  }
+
+  let window_rect = grab.window.to_rect()?;
+  let center_fraction = config.value.window_behavior.drop.center_fraction;
+
+  // Rects that the anchor can be moved between, along with the tiling
+  // direction of their parent. The grabbed window's own rect is the first
+  // entry.
+  let mut targets =
+    vec![(window_rect.clone(), TilingDirection::Horizontal)];
+  targets.extend(anchor_targets(&grab.window, state)?);
+
+  let target_rects = targets
+    .iter()
+    .map(|(rect, _)| rect.clone())
+    .collect::<Vec<_>>();
+
+  // Moves the anchor onto the adjacent target in the given direction. The
+  // anchor is placed at the target's near edge, unless the target is the
+  // grabbed window itself.
+  let adjacent_anchor = |rect: &Rect| {
+    rect
+      .neighbor_in_direction(&target_rects, direction)
+      .and_then(|index| target_rects.get(index))
+      .map(|target| {
+        if *target == window_rect {
+          target.center_point()
+        } else {
+          edge_point(target, &direction.inverse(), center_fraction)
+        }
+      })
+  };
+
+  let current_target = targets
+    .iter()
+    .find(|(rect, _)| rect.contains_point(&grab.anchor));
+
+  let next_anchor = match current_target {
+    Some((rect, tiling_direction)) if *rect != window_rect => {
+      let position = drop_position(
+        &grab.anchor,
+        rect,
+        tiling_direction,
+        center_fraction,
+      );
+
+      if position == DropPosition::from_direction(direction) {
+        adjacent_anchor(rect)
+      } else if position
+        == DropPosition::from_direction(&direction.inverse())
+        && center_fraction > 0.
+      {
+        Some(rect.center_point())
+      } else {
+        Some(edge_point(rect, direction, center_fraction))
+      }
+    }
+    _ => adjacent_anchor(&window_rect),
+  };
+
+  if let Some(next_anchor) = next_anchor {
+    set_cursor_position(&next_anchor);
+
+    state.keyboard_grab = Some(KeyboardGrab {
+      anchor: next_anchor,
+      ..grab
+    });
+  }
+
+  Ok(())
+}
+
+/// Drops the grabbed window at its anchor. Uses the same placement as
+/// when a tiling window is dragged and dropped with the mouse.
+///
+/// Dropping the window onto itself leaves it in place.
+pub fn confirm_grab(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(grab) = state.keyboard_grab.take() else {
+    return Ok(());
+  };
+
+  if grab.window.is_detached()
+    || grab.window.to_rect()?.contains_point(&grab.anchor)
+  {
+    return Ok(());
+  }
+
+  info!("Dropping grabbed window: {}", grab.window);
+
+  let parent = grab.window.parent().context("No parent.")?;
+
+  // Record the window's slot so that the drop can be undone.
+  let slot = grab
+    .window
+    .as_tiling_window()
+    .and_then(WindowSlot::from_window);
+
+  // Transition the window to be floating, same as it would be while
+  // dragged with the mouse.
+  let window = update_window_state(
+    grab.window.clone(),
+    WindowState::Floating(FloatingStateConfig {
+      centered: false,
+      ..config.value.window_behavior.state_defaults.floating
+    }),
+    state,
+    config,
+  )?;
+
+  // Flatten the parent split container if it only contains the window.
+  if let Some(split_parent) = parent.as_split() {
+    if split_parent.child_count() == 1 {
+      let grandparent = split_parent.parent().context("No parent.")?;
+      flatten_split_container(split_parent.clone())?;
+      state.pending_sync.queue_container_to_redraw(grandparent);
+    }
+  }
+
+  let window = window
+    .as_non_tiling_window()
+    .context("Invalid window state.")?
+    .clone();
+
+  let (drag_result, _) =
+    drop_as_tiling_window(&window, &grab.anchor, state, config)?;
+
+  // Drops that snap back leave the layout unchanged, so there's nothing
+  // to undo.
+  if let Some(slot) = slot.filter(|_| drag_result == DragResult::Tiled) {
+    state
+      .layout_history
+      .push(slot, config.value.general.undo_limit);
+  }
+
+  Ok(())
+}
+
+/// Cancels the current grab (if any). The window's position in the tree
+/// isn't changed while grabbed, so it's left where it was.
+pub fn cancel_grab(state: &mut WmState) {
+  if let Some(grab) = state.keyboard_grab.take() {
+    info!("Canceling window grab: {}", grab.window);
+
+    if let Some(cursor_position) = grab.cursor_position {
+      set_cursor_position(&cursor_position);
+    }
+  }
+}
+
+/// Gets the rects that the drop anchor can be moved onto, along with the
+/// tiling direction of their parent.
+///
+/// These are the tiling windows of displayed workspaces (other than the
+/// grabbed window), or the workspace itself if it has none.
+fn anchor_targets(
+  grabbed_window: &WindowContainer,
+  state: &WmState,
+) -> anyhow::Result<Vec<(Rect, TilingDirection)>> {
+  let mut targets = Vec::new();
+
+  for workspace in state.workspaces() {
+    if !workspace.is_displayed() {
+      continue;
+    }
+
+    let windows = workspace
+      .descendants()
+      .filter(|descendant| {
+        descendant.is_tiling_window()
+          && descendant.id() != grabbed_window.id()
+      })
+      .collect::<Vec<_>>();
+
+    if windows.is_empty() {
+      targets.push((workspace.to_rect()?, workspace.tiling_direction()));
+    }
+
+    for window in windows {
+      let tiling_direction = window
+        .parent()
+        .and_then(|parent| parent.as_direction_container().ok())
+        .map_or(TilingDirection::Horizontal, |parent| {
+          parent.tiling_direction()
+        });
+
+      targets.push((window.to_rect()?, tiling_direction));
+    }
+  }
+
+  Ok(targets)
+}
+
+/// Gets a point within the drop zone of the rect's edge in the given
+/// direction. This is midway between the center zone and the edge.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn edge_point(
+  rect: &Rect,
+  direction: &Direction,
+  center_fraction: f32,
+) -> Point {
+  let center = rect.center_point();
+  let factor = (1. + center_fraction.clamp(0., 1.)) / 4.;
+
+  let offset_x = (rect.width() as f32 * factor) as i32;
+  let offset_y = (rect.height() as f32 * factor) as i32;
+
+  match direction {
+    Direction::Left => Point {
+      x: center.x - offset_x,
+      y: center.y,
+    },
+    Direction::Right => Point {
+      x: center.x + offset_x,
+      y: center.y,
+    },
+    Direction::Up => Point {
+      x: center.x,
+      y: center.y - offset_y,
+    },
+    Direction::Down => Point {
+      x: center.x,
+      y: center.y + offset_y,
+    },
+  }
+}
+
+fn set_cursor_position(position: &Point) {
+  if let Err(err) = Platform::set_cursor_pos(position.x, position.y) {
+    warn!("Failed to set cursor position: {}", err);
+  }
+}
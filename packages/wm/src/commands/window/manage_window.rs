@@ -1,19 +1,26 @@
 use anyhow::Context;
 use tracing::info;
 use wm_common::{
-  try_warn, LengthValue, RectDelta, WindowRuleEvent, WindowState, WmEvent,
+  try_warn, GapsConfig, InsertionMode, LengthValue, RectDelta,
+  WindowRuleEvent, WindowState, WmEvent,
 };
 use wm_platform::NativeWindow;
 
 use crate::{
   commands::{
-    container::{attach_container, set_focused_descendant},
-    window::run_window_rules,
+    container::{
+      attach_container, set_focused_descendant, wrap_in_split_container,
+    },
+    window::{apply_floating_placement, run_window_rules},
   },
   models::{
-    Container, Monitor, NonTilingWindow, TilingWindow, WindowContainer,
+    Container, Monitor, NonTilingWindow, SplitContainer, TilingContainer,
+    TilingWindow, WindowContainer,
+  },
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters,
+    TilingSizeGetters, WindowGetters,
   },
-  traits::{CommonGetters, PositionGetters, WindowGetters},
   user_config::UserConfig,
   wm_state::WmState,
 };
@@ -33,6 +40,16 @@ pub fn manage_window(
   // rules will be run as if the window is focused.
   set_focused_descendant(&window.clone().into(), None);
 
+  // Lock the window to the aspect ratio it was created with (e.g. for
+  // video players that would otherwise letterbox their content).
+  if config.has_aspect_ratio_rule(&window)? {
+    window.set_aspect_ratio_lock(window.native().aspect_ratio()?);
+  }
+
+  // Windows can be floating from the start, in which case the floating
+  // placement is applied before the window rules are run.
+  apply_floating_placement(&window, state, config)?;
+
   // Window might be detached if `ignore` command has been invoked.
   let updated_window = run_window_rules(
     window.clone(),
@@ -94,9 +111,9 @@ fn create_window(
 
   // Attach the new window as the first child of the target parent (if
   // provided), otherwise, add as a sibling of the focused container.
-  let (target_parent, target_index) = match target_parent {
-    Some(parent) => (parent, 0),
-    None => insertion_target(&window_state, state)?,
+  let (target_parent, target_index, spiral_sibling) = match target_parent {
+    Some(parent) => (parent, 0, None),
+    None => insertion_target(&window_state, state, config)?,
   };
 
   let target_workspace =
@@ -150,6 +167,7 @@ fn create_window(
       gaps_config,
       Vec::new(),
       None,
+      None,
     )
     .into(),
     _ => NonTilingWindow::new(
@@ -163,15 +181,25 @@ fn create_window(
       false,
       Vec::new(),
       None,
+      None,
     )
     .into(),
   };
 
-  attach_container(
-    &window_container.clone().into(),
-    &target_parent,
-    Some(target_index),
-  )?;
+  match (&window_container, spiral_sibling) {
+    (WindowContainer::TilingWindow(window), Some(sibling)) => {
+      attach_spiral(
+        &window.clone().into(),
+        &sibling,
+        target_workspace.gaps_config(),
+      )?;
+    }
+    _ => attach_container(
+      &window_container.clone().into(),
+      &target_parent,
+      Some(target_index),
+    )?,
+  }
 
   // The OS might spawn the window on a different monitor to the target
   // parent, so adjustments might need to be made because of DPI.
@@ -240,11 +268,14 @@ fn window_state_to_create(
 ///      tiling window found.
 ///   3. If no tiling windows exist, append to the workspace.
 ///
-/// Returns tuple of (parent container, insertion index).
+/// Returns tuple of (parent container, insertion index, spiral sibling).
+/// The spiral sibling is the tiling window to insert after with the
+/// spiral insertion mode (see `attach_spiral`).
 fn insertion_target(
   window_state: &WindowState,
   state: &WmState,
-) -> anyhow::Result<(Container, usize)> {
+  config: &UserConfig,
+) -> anyhow::Result<(Container, usize, Option<TilingContainer>)> {
   let focused_container =
     state.focused_container().context("No focused container.")?;
 
@@ -262,9 +293,15 @@ fn insertion_target(
     };
 
     if let Some(sibling) = sibling {
+      let spiral_sibling = (config.value.window_behavior.insertion_mode
+        == InsertionMode::Spiral)
+        .then(|| sibling.as_tiling_container())
+        .transpose()?;
+
       return Ok((
         sibling.parent().context("No parent.")?,
         sibling.index() + 1,
+        spiral_sibling,
       ));
     }
   }
@@ -273,5 +310,138 @@ fn insertion_target(
   Ok((
     focused_workspace.clone().into(),
     focused_workspace.child_count(),
+    None,
   ))
 }
+
+/// Inserts a new tiling window after the sibling for the spiral insertion
+/// mode.
+///
+/// The window and the sibling are wrapped in a split container that's
+/// perpendicular to their parent's tiling direction, and the new window
+/// takes half of the sibling's space. Repeating this for each new window
+/// alternates the split directions, e.g. H[1 V[2 H[3 4]]].
+///
+/// The window is attached first, so that the tree is only changed once
+/// the window exists.
+fn attach_spiral(
+  window: &TilingContainer,
+  sibling: &TilingContainer,
+  gaps_config: GapsConfig,
+) -> anyhow::Result<()> {
+  let parent = sibling
+    .parent()
+    .and_then(|parent| parent.as_direction_container().ok())
+    .context("No direction container.")?;
+
+  // Sizes from before the insertion. These are restored after wrapping,
+  // so that only the sibling's space is split.
+  let prev_tiling_sizes = parent
+    .tiling_children()
+    .map(|child| (child.id(), child.tiling_size()))
+    .collect::<Vec<_>>();
+
+  attach_container(
+    &window.clone().into(),
+    &parent.clone().into(),
+    Some(sibling.index() + 1),
+  )?;
+
+  // No need to split if the sibling was an only child. The new window is
+  // inserted in the parent's tiling direction instead.
+  if prev_tiling_sizes.len() == 1 {
+    return Ok(());
+  }
+
+  let split_container =
+    SplitContainer::new(parent.tiling_direction().inverse(), gaps_config);
+
+  wrap_in_split_container(
+    &split_container,
+    &parent.clone().into(),
+    &[sibling.clone(), window.clone()],
+  )?;
+
+  for (id, tiling_size) in prev_tiling_sizes {
+    let container = if id == sibling.id() {
+      split_container.clone().into()
+    } else {
+      match parent.child_by_id(&id) {
+        Some(child) => child.as_tiling_container()?,
+        None => continue,
+      }
+    };
+
+    container.set_tiling_size(tiling_size);
+  }
+
+  sibling.set_tiling_size(0.5);
+  window.set_tiling_size(0.5);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::test_utils::{attach, tiling_window, workspace};
+
+  #[test]
+  fn spiral_alternates_split_directions() {
+    // H[1 V[2 H[3 V[4 5]]]]
+    let workspace = workspace(TilingDirection::Horizontal);
+    let windows = (1..=5).map(tiling_window).collect::<Vec<_>>();
+
+    attach(windows[0].clone(), workspace.clone());
+
+    for pair in windows.windows(2) {
+      attach_spiral(
+        &pair[1].clone().into(),
+        &pair[0].clone().into(),
+        workspace.gaps_config(),
+      )
+      .unwrap();
+    }
+
+    let parent_directions = windows
+      .iter()
+      .map(|window| {
+        window
+          .direction_container()
+          .expect("No direction container.")
+          .tiling_direction()
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      parent_directions,
+      [
+        TilingDirection::Horizontal,
+        TilingDirection::Vertical,
+        TilingDirection::Horizontal,
+        TilingDirection::Vertical,
+        TilingDirection::Vertical,
+      ]
+    );
+
+    // Each split is nested one level deeper than the last.
+    let depths = windows
+      .iter()
+      .map(|window| {
+        window
+          .ancestors()
+          .take_while(|ancestor| !ancestor.is_workspace())
+          .count()
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(depths, [0, 1, 2, 3, 3]);
+
+    // Each new window takes half of the previous window's space.
+    for window in &windows[1..] {
+      assert!((window.tiling_size() - 0.5).abs() < f32::EPSILON);
+    }
+  }
+}
@@ -1,21 +1,37 @@
+mod apply_floating_placement;
+mod float_all_of_app;
+mod grab_window;
 mod ignore_window;
 mod manage_window;
+mod move_sticky_windows;
 mod move_window_in_direction;
+mod move_window_to_monitor_in_direction;
 mod move_window_to_workspace;
+mod peek_window;
+mod rescue_all_floating;
 mod resize_window;
 mod run_window_rules;
 mod set_window_position;
 mod set_window_size;
+mod set_window_sticky;
 mod unmanage_window;
 mod update_window_state;
 
+pub use apply_floating_placement::*;
+pub use float_all_of_app::*;
+pub use grab_window::*;
 pub use ignore_window::*;
 pub use manage_window::*;
+pub use move_sticky_windows::*;
 pub use move_window_in_direction::*;
+pub use move_window_to_monitor_in_direction::*;
 pub use move_window_to_workspace::*;
+pub use peek_window::*;
+pub use rescue_all_floating::*;
 pub use resize_window::*;
 pub use run_window_rules::*;
 pub use set_window_position::*;
 pub use set_window_size::*;
+pub use set_window_sticky::*;
 pub use unmanage_window::*;
 pub use update_window_state::*;
@@ -0,0 +1,96 @@
+use tracing::info;
+use wm_common::WindowState;
+
+use crate::{
+  commands::container::move_container_within_tree,
+  models::Container,
+  traits::{CommonGetters, WindowGetters},
+  wm_state::WmState,
+};
+
+/// Moves sticky floating windows on hidden workspaces to the displayed
+/// workspace of their monitor.
+///
+/// This is run whenever the displayed workspace of a monitor might have
+/// changed. Both workspaces are on the same monitor, so the windows keep
+/// their position on screen.
+pub fn move_sticky_windows(state: &mut WmState) -> anyhow::Result<()> {
+  for monitor in state.monitors() {
+    let Some(displayed_workspace) = monitor.displayed_workspace() else {
+      continue;
+    };
+
+    let sticky_windows = monitor
+      .workspaces()
+      .into_iter()
+      .filter(|workspace| workspace.id() != displayed_workspace.id())
+      .flat_map(|workspace| workspace.children())
+      .filter_map(|child| match child {
+        Container::NonTilingWindow(window)
+          if window.is_sticky()
+            && matches!(window.state(), WindowState::Floating(_)) =>
+        {
+          Some(window)
+        }
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    for window in sticky_windows {
+      info!("Moving sticky window to workspace: {displayed_workspace}");
+
+      move_container_within_tree(
+        &window.clone().into(),
+        &displayed_workspace.clone().into(),
+        displayed_workspace.child_count(),
+        state,
+      )?;
+
+      state.pending_sync.queue_container_to_redraw(window);
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, TilingDirection};
+
+  use super::*;
+  use crate::{
+    commands::container::set_focused_descendant,
+    test_utils::{attach, non_tiling_window, wm_state, workspace},
+    traits::PositionGetters,
+  };
+
+  #[test]
+  fn keeps_rect_when_switching_workspaces_twice() {
+    let workspace_1 = workspace(TilingDirection::Horizontal);
+    let workspace_2 = workspace(TilingDirection::Horizontal);
+    let window = non_tiling_window(
+      1,
+      WindowState::Floating(FloatingStateConfig::default()),
+    );
+    window.set_sticky(true);
+
+    attach(window.clone(), workspace_1.clone());
+
+    let mut state = wm_state(&workspace_1);
+    let monitor = workspace_1.monitor().expect("No monitor.");
+    attach(workspace_2.clone(), monitor);
+
+    let rect = window.to_rect().unwrap();
+
+    for workspace in [&workspace_2, &workspace_1] {
+      set_focused_descendant(&workspace.clone().into(), None);
+      move_sticky_windows(&mut state).unwrap();
+
+      assert_eq!(
+        window.workspace().map(|workspace| workspace.id()),
+        Some(workspace.id())
+      );
+      assert_eq!(window.to_rect().unwrap(), rect);
+    }
+  }
+}
@@ -0,0 +1,126 @@
+use anyhow::Context;
+use wm_common::{Direction, Point, Rect};
+
+use crate::{
+  commands::container::{
+    move_container_within_tree, set_focused_descendant,
+  },
+  models::WindowContainer,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  wm_state::WmState,
+};
+
+/// Moves a window to the displayed workspace of the monitor in the given
+/// direction.
+///
+/// The window's floating placement keeps its relative position on the
+/// monitor (e.g. a window centered in the right half of one monitor ends
+/// up centered in the right half of the other). Tiling windows are
+/// inserted at the edge of the workspace nearest to the origin monitor.
+pub fn move_window_to_monitor_in_direction(
+  window: &WindowContainer,
+  direction: &Direction,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let parent = window.parent().context("No parent.")?;
+  let workspace = window.workspace().context("No workspace.")?;
+  let monitor = workspace.monitor().context("No monitor.")?;
+
+  let Some(target_workspace) = state
+    .monitor_in_direction(&monitor, direction)?
+    .and_then(|monitor| monitor.displayed_workspace())
+  else {
+    return Ok(());
+  };
+
+  // Since the window is crossing monitors, adjustments might need to be
+  // made because of DPI.
+  if monitor.has_dpi_difference(&target_workspace.clone().into())? {
+    window.set_has_pending_dpi_adjustment(true);
+  }
+
+  window.set_floating_placement(proportional_placement(
+    &window.floating_placement(),
+    &workspace.to_rect()?,
+    &target_workspace.to_rect()?,
+  ));
+
+  if let WindowContainer::NonTilingWindow(window) = &window {
+    window.set_insertion_target(None);
+  }
+
+  let target_index = match direction {
+    Direction::Down | Direction::Right => 0,
+    _ => target_workspace.child_count(),
+  };
+
+  // Focus should be reassigned within the original workspace after the
+  // window is moved out.
+  let focus_target = state.focus_target_after_removal(window);
+
+  move_container_within_tree(
+    &window.clone().into(),
+    &target_workspace.clone().into(),
+    target_index,
+    state,
+  )?;
+
+  if let Some(focus_target) = focus_target {
+    set_focused_descendant(&focus_target, Some(&workspace.into()));
+  }
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(window.clone())
+    .queue_containers_to_redraw(target_workspace.tiling_children())
+    .queue_containers_to_redraw(parent.tiling_children())
+    .queue_cursor_jump()
+    .queue_workspace_to_reorder(target_workspace);
+
+  Ok(())
+}
+
+/// Maps the center of a rect from one outer rect onto another, such that
+/// it keeps the same relative position.
+fn proportional_placement(
+  rect: &Rect,
+  source_rect: &Rect,
+  target_rect: &Rect,
+) -> Rect {
+  let center = rect.center_point();
+
+  let map_axis = |value: i32, source: (i32, i32), target: (i32, i32)| {
+    let (source_start, source_length) = source;
+    let (target_start, target_length) = target;
+
+    if source_length == 0 {
+      return target_start + target_length / 2;
+    }
+
+    let offset = i64::from(value - source_start)
+      * i64::from(target_length)
+      / i64::from(source_length);
+
+    target_start + i32::try_from(offset).unwrap_or(target_length / 2)
+  };
+
+  let target_center = Point {
+    x: map_axis(
+      center.x,
+      (source_rect.left, source_rect.width()),
+      (target_rect.left, target_rect.width()),
+    ),
+    y: map_axis(
+      center.y,
+      (source_rect.top, source_rect.height()),
+      (target_rect.top, target_rect.height()),
+    ),
+  };
+
+  rect
+    .translate_to_coordinates(
+      target_center.x - rect.width() / 2,
+      target_center.y - rect.height() / 2,
+    )
+    .clamp(target_rect)
+}
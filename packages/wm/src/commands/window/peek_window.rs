@@ -0,0 +1,176 @@
+use anyhow::{bail, Context};
+use tracing::info;
+use wm_platform::ZOrder;
+
+use crate::{
+  models::WindowContainer,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Temporarily enlarges the window by the given scale factor, centered on
+/// its current position. Calling this again on the same window reverts
+/// the peek.
+///
+/// The window is only repositioned natively, so its place in the tree is
+/// left unchanged. This is a no-op while the window is being dragged.
+///
+/// The peek is reverted once the window is redrawn or loses focus.
+pub fn peek_window(
+  window: &WindowContainer,
+  scale: f32,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if scale.is_nan() || scale < 1. {
+    bail!("Invalid peek scale '{scale}'. Must be at least 1.");
+  }
+
+  if window.active_drag().is_some() || !toggle_peeked_window(window, state)
+  {
+    return Ok(());
+  }
+
+  info!("Peeking window: {window}");
+
+  let monitor_rect = window
+    .monitor()
+    .context("No monitor.")?
+    .native()
+    .working_rect()?
+    .clone();
+
+  let rect = window
+    .to_rect()?
+    .scaled(scale)
+    .clamp(&monitor_rect)
+    .apply_delta(&window.total_border_delta()?, None);
+
+  window.native().set_position(
+    &window.state(),
+    &rect,
+    &ZOrder::TopMost,
+    true,
+    &config.value.general.hide_method,
+    window.has_pending_dpi_adjustment(),
+  )?;
+
+  Ok(())
+}
+
+/// Marks the window as the peeked window, reverting any other peeked
+/// window. If the window is already peeked, its peek is reverted instead.
+///
+/// Returns whether the window is now peeked.
+fn toggle_peeked_window(
+  window: &WindowContainer,
+  state: &mut WmState,
+) -> bool {
+  let was_peeked = state
+    .peeked_window
+    .as_ref()
+    .is_some_and(|peeked| peeked.id() == window.id());
+
+  // Only a single window can be peeked at a time.
+  unpeek_window(state);
+
+  if !was_peeked {
+    state.peeked_window = Some(window.clone());
+  }
+
+  !was_peeked
+}
+
+/// Reverts the currently peeked window (if any) back to its regular
+/// position.
+pub fn unpeek_window(state: &mut WmState) {
+  if let Some(peeked_window) = state.peeked_window.take() {
+    info!("Reverting window peek: {peeked_window}");
+
+    // The window might've been unmanaged while peeked.
+    if !peeked_window.is_detached() {
+      state.pending_sync.queue_container_to_redraw(peeked_window);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{ParsedConfig, TilingDirection};
+
+  use super::*;
+  use crate::{
+    models::TilingWindow,
+    test_utils::{attach, tiling_window, wm_state, workspace},
+    traits::TilingSizeGetters,
+  };
+
+  #[test]
+  fn toggles_peek_without_changing_tree() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let windows = (1..=2).map(tiling_window).collect::<Vec<_>>();
+
+    for window in &windows {
+      attach(window.clone(), workspace.clone());
+    }
+
+    let mut state = wm_state(&workspace);
+
+    let tree_position = |window: &TilingWindow| {
+      (
+        window.parent().map(|parent| parent.id()),
+        window.index(),
+        window.tiling_size(),
+      )
+    };
+
+    let positions_before =
+      windows.iter().map(tree_position).collect::<Vec<_>>();
+
+    let peeked_id = |state: &WmState| {
+      state.peeked_window.as_ref().map(CommonGetters::id)
+    };
+
+    let first: WindowContainer = windows[0].clone().into();
+    let second: WindowContainer = windows[1].clone().into();
+
+    assert!(toggle_peeked_window(&first, &mut state));
+    assert_eq!(peeked_id(&state), Some(first.id()));
+
+    // Peeking another window replaces the current peek.
+    assert!(toggle_peeked_window(&second, &mut state));
+    assert_eq!(peeked_id(&state), Some(second.id()));
+
+    // Peeking the same window again reverts it.
+    assert!(!toggle_peeked_window(&second, &mut state));
+    assert_eq!(peeked_id(&state), None);
+
+    let positions_after =
+      windows.iter().map(tree_position).collect::<Vec<_>>();
+
+    assert_eq!(positions_after, positions_before);
+  }
+
+  #[test]
+  fn rejects_invalid_scale() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(1);
+    attach(window.clone(), workspace.clone());
+
+    let mut state = wm_state(&workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    for scale in [0.5, f32::NAN] {
+      assert!(peek_window(
+        &window.clone().into(),
+        scale,
+        &mut state,
+        &config
+      )
+      .is_err());
+    }
+
+    assert!(state.peeked_window.is_none());
+  }
+}
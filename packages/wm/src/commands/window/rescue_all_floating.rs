@@ -0,0 +1,102 @@
+use anyhow::Context;
+use tracing::info;
+use wm_common::{Point, Rect, WindowState};
+
+use crate::{
+  traits::{CommonGetters, WindowGetters},
+  wm_state::WmState,
+};
+
+/// Moves all floating windows that are partially or fully off-screen back
+/// within the working area of their monitor.
+///
+/// Windows that are larger than the working area are shrunk to fit.
+pub fn rescue_all_floating(state: &mut WmState) -> anyhow::Result<()> {
+  let floating_windows = state
+    .windows()
+    .into_iter()
+    .filter(|window| matches!(window.state(), WindowState::Floating(_)));
+
+  for window in floating_windows {
+    let working_rect = window
+      .monitor()
+      .context("No monitor.")?
+      .native()
+      .working_rect()?
+      .clone();
+
+    let placement = window.floating_placement();
+    let new_placement = rescued_placement(&placement, &working_rect);
+
+    if new_placement != placement {
+      info!("Moving floating window on-screen: {window}");
+
+      window.set_floating_placement(new_placement);
+      state.pending_sync.queue_container_to_redraw(window);
+    }
+  }
+
+  Ok(())
+}
+
+/// Gets the placement moved (and shrunk if needed) to fit within the
+/// working area.
+fn rescued_placement(placement: &Rect, working_rect: &Rect) -> Rect {
+  let width = placement.width().min(working_rect.width());
+  let height = placement.height().min(working_rect.height());
+
+  // Clamp the top-left corner such that the whole window fits within
+  // the working area.
+  let position = Point {
+    x: placement.x(),
+    y: placement.y(),
+  }
+  .clamp_to_rect(&Rect::from_ltrb(
+    working_rect.left,
+    working_rect.top,
+    working_rect.right - width,
+    working_rect.bottom - height,
+  ));
+
+  Rect::from_xy(position.x, position.y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn brings_off_screen_placements_on_screen() {
+    let working_rect = Rect::from_xy(0, 0, 1920, 1040);
+
+    let placements = [
+      // Fully off-screen to the left.
+      Rect::from_xy(-2000, 100, 800, 600),
+      // Partially off-screen to the bottom right.
+      Rect::from_xy(1500, 900, 800, 600),
+      // Off-screen on a monitor that no longer exists above.
+      Rect::from_xy(300, -1200, 800, 600),
+      // Larger than the working area.
+      Rect::from_xy(-100, -100, 2500, 1500),
+    ];
+
+    let expected = [
+      Rect::from_xy(0, 100, 800, 600),
+      Rect::from_xy(1120, 440, 800, 600),
+      Rect::from_xy(300, 0, 800, 600),
+      Rect::from_xy(0, 0, 1920, 1040),
+    ];
+
+    for (placement, expected) in placements.iter().zip(expected) {
+      assert_eq!(rescued_placement(placement, &working_rect), expected);
+    }
+  }
+
+  #[test]
+  fn keeps_on_screen_placement() {
+    let working_rect = Rect::from_xy(1920, 0, 1920, 1040);
+    let placement = Rect::from_xy(2000, 200, 800, 600);
+
+    assert_eq!(rescued_placement(&placement, &working_rect), placement);
+  }
+}
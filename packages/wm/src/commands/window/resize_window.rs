@@ -1,85 +1,68 @@
-use wm_common::LengthValue;
+use wm_common::{LengthValue, Rect};
 
 use super::set_window_size;
 use crate::{
-  models::WindowContainer,
+  models::{TilingContainer, WindowContainer},
   traits::{CommonGetters, PositionGetters, TilingSizeGetters},
+  user_config::UserConfig,
   wm_state::WmState,
 };
 
+/// Resizes a window by the given width and height deltas.
+///
+/// Percentage deltas are resolved against the available length of the
+/// container being resized. For tiling windows, this is the length of
+/// the parent of the container that gets resized (which might be an
+/// ancestor split container), excluding inner gaps.
+///
+/// The resulting size is clamped to `window_behavior.size_limits`. For
+/// tiling windows, the minimum size is also kept free for each sibling of
+/// the container that gets resized.
+///
+/// Returns the width and height deltas (in pixels) that were applied.
 pub fn resize_window(
   window: &WindowContainer,
   width_delta: Option<LengthValue>,
   height_delta: Option<LengthValue>,
   state: &mut WmState,
-) -> anyhow::Result<()> {
+  config: &UserConfig,
+) -> anyhow::Result<(i32, i32)> {
   let window_rect = window.to_rect()?;
+  let size_limits = &config.value.window_behavior.size_limits;
 
   let target_width = match width_delta {
-    Some(delta) => {
-      let parent_width = match window.as_tiling_container() {
-        Ok(tiling_window) => tiling_window
-          .container_to_resize(true)?
-          .and_then(|container| container.parent())
-          .and_then(|parent| {
-            parent.to_rect().ok().map(|rect| rect.width())
-          })
-          .and_then(|parent_width| {
-            let (horizontal_gap, _) = tiling_window.inner_gaps().ok()?;
-
-            #[allow(
-              clippy::cast_possible_wrap,
-              clippy::cast_possible_truncation
-            )]
-            Some(
-              parent_width
-                - horizontal_gap
-                  * tiling_window.tiling_siblings().count() as i32,
-            )
-          }),
-        _ => window.parent().and_then(|parent| {
-          parent.to_rect().ok().map(|rect| rect.width())
-        }),
-      };
-
-      parent_width.map(|parent_width| {
-        window_rect.width() + delta.to_px(parent_width, None)
-      })
-    }
+    Some(delta) => available_length(window, true)?.map(|parent_width| {
+      clamp_length(
+        window_rect.width() + delta.to_px(parent_width, None),
+        window_rect.width(),
+        size_limits.min_width,
+        length_upper_bound(
+          window,
+          parent_width,
+          size_limits.min_width,
+          size_limits.max_width,
+          true,
+        ),
+      )
+    }),
     _ => None,
   };
 
   let target_height = match height_delta {
-    Some(delta) => {
-      let parent_height = match window.as_tiling_container() {
-        Ok(tiling_window) => tiling_window
-          .container_to_resize(false)?
-          .and_then(|container| container.parent())
-          .and_then(|parent| {
-            parent.to_rect().ok().map(|rect| rect.width())
-          })
-          .and_then(|parent_height| {
-            let (_, vertical_gap) = tiling_window.inner_gaps().ok()?;
-
-            #[allow(
-              clippy::cast_possible_wrap,
-              clippy::cast_possible_truncation
-            )]
-            Some(
-              parent_height
-                - vertical_gap
-                  * tiling_window.tiling_siblings().count() as i32,
-            )
-          }),
-        _ => window.parent().and_then(|parent| {
-          parent.to_rect().ok().map(|rect| rect.width())
-        }),
-      };
-
-      parent_height.map(|parent_height| {
-        window_rect.height() + delta.to_px(parent_height, None)
-      })
-    }
+    Some(delta) => available_length(window, false)?.map(|parent_height| {
+      clamp_length(
+        window_rect.height() + delta.to_px(parent_height, None),
+        window_rect.height(),
+        size_limits.min_height,
+        length_upper_bound(
+          window,
+          parent_height,
+          size_limits.min_height,
+          size_limits.max_height,
+          false,
+        ),
+      )
+    }),
     _ => None,
   };
 
@@ -90,5 +73,220 @@ pub fn resize_window(
     state,
   )?;
 
-  Ok(())
+  let new_rect = window.to_rect()?;
+
+  Ok((
+    new_rect.width() - window_rect.width(),
+    new_rect.height() - window_rect.height(),
+  ))
+}
+
+/// Clamps a target length to the given bounds. A bound of 0 is treated as
+/// no limit.
+///
+/// Changes toward the bounds are always allowed, such that a window that's
+/// already out of bounds isn't forced to resize.
+fn clamp_length(
+  target_length: i32,
+  current_length: i32,
+  min_length: i32,
+  max_length: i32,
+) -> i32 {
+  if target_length < current_length {
+    target_length.max(min_length.min(current_length))
+  } else if max_length > 0 {
+    target_length.min(max_length.max(current_length))
+  } else {
+    target_length
+  }
+}
+
+/// Gets the maximum length (in pixels) that the window can be resized
+/// to, or 0 if there is no limit.
+///
+/// For tiling windows, this leaves the minimum length for each sibling of
+/// the container that gets resized. If that isn't possible, the window
+/// is limited to the minimum length itself.
+fn length_upper_bound(
+  window: &WindowContainer,
+  available_length: i32,
+  min_length: i32,
+  max_length: i32,
+  is_width_resize: bool,
+) -> i32 {
+  let sibling_count = window
+    .as_tiling_container()
+    .ok()
+    .and_then(|tiling_window| {
+      tiling_window.container_to_resize(is_width_resize).ok()?
+    })
+    .map_or(0, |container| container.tiling_siblings().count());
+
+  if sibling_count == 0 || min_length == 0 {
+    return max_length;
+  }
+
+  #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+  let max_for_siblings =
+    (available_length - min_length * sibling_count as i32).max(min_length);
+
+  if max_length > 0 {
+    max_length.min(max_for_siblings)
+  } else {
+    max_for_siblings
+  }
+}
+
+/// Gets the length (in pixels) that a percentage resize of the window is
+/// relative to.
+fn available_length(
+  window: &WindowContainer,
+  is_width_resize: bool,
+) -> anyhow::Result<Option<i32>> {
+  let length = match window.as_tiling_container() {
+    Ok(tiling_window) => tiling_window
+      .container_to_resize(is_width_resize)?
+      .and_then(|container_to_resize| {
+        let parent_rect = container_to_resize.parent()?.to_rect().ok()?;
+        let inner_gaps = tiling_window.inner_gaps().ok()?;
+
+        Some(length_excluding_gaps(
+          &container_to_resize,
+          &parent_rect,
+          inner_gaps,
+          is_width_resize,
+        ))
+      }),
+    _ => window.parent().and_then(|parent| {
+      parent.to_rect().ok().map(|rect| {
+        if is_width_resize {
+          rect.width()
+        } else {
+          rect.height()
+        }
+      })
+    }),
+  };
+
+  Ok(length)
+}
+
+/// Gets the length of the parent rect that's shared between the container
+/// to resize and its siblings, excluding the inner gaps between them.
+fn length_excluding_gaps(
+  container_to_resize: &TilingContainer,
+  parent_rect: &Rect,
+  (horizontal_gap, vertical_gap): (i32, i32),
+  is_width_resize: bool,
+) -> i32 {
+  let (parent_length, inner_gap) = if is_width_resize {
+    (parent_rect.width(), horizontal_gap)
+  } else {
+    (parent_rect.height(), vertical_gap)
+  };
+
+  #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+  let sibling_count = container_to_resize.tiling_siblings().count() as i32;
+
+  parent_length - inner_gap * sibling_count
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::{
+    models::TilingWindow,
+    test_utils::{attach, split, tiling_window, workspace},
+  };
+
+  #[test]
+  fn available_length_of_nested_splits() {
+    // H[0 V[1 2 H[3 4]]]
+    let workspace = workspace(TilingDirection::Horizontal);
+    let vertical_split = split(TilingDirection::Vertical);
+    let horizontal_split = split(TilingDirection::Horizontal);
+    let windows = (0..5).map(tiling_window).collect::<Vec<_>>();
+
+    attach(windows[0].clone(), workspace.clone());
+    attach(vertical_split.clone(), workspace.clone());
+    attach(windows[1].clone(), vertical_split.clone());
+    attach(windows[2].clone(), vertical_split.clone());
+    attach(horizontal_split.clone(), vertical_split.clone());
+    attach(windows[3].clone(), horizontal_split.clone());
+    attach(windows[4].clone(), horizontal_split.clone());
+
+    let parent_rect = Rect::from_xy(0, 0, 1000, 800);
+    let inner_gaps = (10, 20);
+
+    let length = |window: &TilingWindow, is_width_resize: bool| {
+      let container_to_resize = window
+        .container_to_resize(is_width_resize)
+        .unwrap()
+        .expect("No container to resize.");
+
+      length_excluding_gaps(
+        &container_to_resize,
+        &parent_rect,
+        inner_gaps,
+        is_width_resize,
+      )
+    };
+
+    // Resizes the vertical split, which has 1 sibling in the workspace.
+    assert_eq!(length(&windows[1], true), 990);
+
+    // Resizes the window itself, which has 2 siblings.
+    assert_eq!(length(&windows[1], false), 760);
+
+    // Resizes the nested horizontal split, which has 2 siblings.
+    assert_eq!(length(&windows[3], false), 760);
+
+    // Resizes the window itself, which has 1 sibling.
+    assert_eq!(length(&windows[3], true), 990);
+  }
+
+  #[test]
+  fn clamps_length_to_bounds() {
+    // Shrinking and growing past the bounds.
+    assert_eq!(clamp_length(50, 300, 100, 800), 100);
+    assert_eq!(clamp_length(900, 300, 100, 800), 800);
+
+    // Already out of bounds, so only resizing away from them is limited.
+    assert_eq!(clamp_length(50, 80, 100, 800), 80);
+    assert_eq!(clamp_length(1000, 900, 100, 800), 900);
+    assert_eq!(clamp_length(850, 900, 100, 800), 850);
+
+    // A bound of 0 is no limit.
+    assert_eq!(clamp_length(5000, 300, 0, 0), 5000);
+    assert_eq!(clamp_length(-10, 300, 0, 0), 0);
+  }
+
+  #[test]
+  fn upper_bound_leaves_min_length_for_siblings() {
+    // H[0 1 2]
+    let workspace = workspace(TilingDirection::Horizontal);
+    let windows = (0..3).map(tiling_window).collect::<Vec<_>>();
+
+    for window in &windows {
+      attach(window.clone(), workspace.clone());
+    }
+
+    let window: WindowContainer = windows[0].clone().into();
+
+    assert_eq!(length_upper_bound(&window, 1000, 100, 0, true), 800);
+    assert_eq!(length_upper_bound(&window, 1000, 100, 700, true), 700);
+
+    // Siblings can't all fit, so it's limited to its own minimum length
+    // instead of being unbounded.
+    assert_eq!(length_upper_bound(&window, 1000, 600, 0, true), 600);
+
+    // No minimum length to leave for siblings.
+    assert_eq!(length_upper_bound(&window, 1000, 0, 700, true), 700);
+
+    // Height resizes of a window directly in the workspace don't affect
+    // any siblings.
+    assert_eq!(length_upper_bound(&window, 1000, 100, 0, false), 0);
+  }
 }
@@ -0,0 +1,25 @@
+use tracing::info;
+use wm_common::WindowState;
+
+use crate::{models::WindowContainer, traits::WindowGetters};
+
+/// Sets whether the window is sticky. Sticky windows are moved along to
+/// the newly displayed workspace when switching workspaces on their
+/// monitor.
+///
+/// Only floating windows can be made sticky. The flag is cleared once the
+/// window becomes tiling.
+pub fn set_window_sticky(window: &WindowContainer, is_sticky: bool) {
+  let WindowContainer::NonTilingWindow(non_tiling_window) = window else {
+    info!("Ignoring sticky change for tiling window: {window}");
+    return;
+  };
+
+  if is_sticky && !matches!(window.state(), WindowState::Floating(_)) {
+    info!("Only floating windows can be made sticky: {window}");
+    return;
+  }
+
+  info!("Setting window sticky to {is_sticky}: {window}");
+  non_tiling_window.set_sticky(is_sticky);
+}
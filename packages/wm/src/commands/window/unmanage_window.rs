@@ -23,6 +23,7 @@ pub fn unmanage_window(
   let focus_target = state.focus_target_after_removal(&window.clone());
 
   detach_container(window.clone().into())?;
+  state.floating_placed_windows.remove(&window.id());
 
   // After detaching the container, flatten any redundant split containers.
   // For example, in the layout V[1 H[2]] where container 1 is detached to
@@ -2,6 +2,7 @@ use anyhow::Context;
 use tracing::{info, warn};
 use wm_common::WindowState;
 
+use super::apply_floating_placement;
 use crate::{
   commands::container::{
     move_container_within_tree, replace_container, resize_tiling_container,
@@ -29,10 +30,18 @@ pub fn update_window_state(
 
   info!("Updating window state: {:?}.", target_state);
 
-  match target_state {
+  let was_floating = matches!(window.state(), WindowState::Floating(_));
+
+  let window = match target_state {
     WindowState::Tiling => set_tiling(&window, state, config),
     _ => set_non_tiling(window, target_state, state),
+  }?;
+
+  if !was_floating {
+    apply_floating_placement(&window, state, config)?;
   }
+
+  Ok(window)
 }
 
 /// Updates the state of a window to be `WindowState::Tiling`.
@@ -4,7 +4,8 @@ use tracing::info;
 use super::activate_workspace;
 use crate::{
   commands::{
-    container::set_focused_descendant, workspace::deactivate_workspace,
+    container::set_focused_descendant, window::move_sticky_windows,
+    workspace::deactivate_workspace,
   },
   models::WorkspaceTarget,
   traits::CommonGetters,
@@ -65,6 +66,10 @@ pub fn focus_workspace(
     set_focused_descendant(&container_to_focus, None);
     state.pending_sync.queue_focus_change();
 
+    // Sticky windows are moved before checking for empty workspaces, so
+    // that a workspace left with only sticky windows gets destroyed.
+    move_sticky_windows(state)?;
+
     // Display the workspace to switch focus to.
     state
       .pending_sync
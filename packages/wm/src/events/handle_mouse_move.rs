@@ -1,9 +1,21 @@
+use std::time::{Duration, Instant};
+
 use anyhow::Context;
+use tracing::info;
+use wm_common::{
+  ActiveDrag, ActiveDragOperation, Direction, DragEdgeDwell, Point,
+};
 use wm_platform::{MouseMoveEvent, Platform};
 
 use crate::{
-  commands::container::set_focused_descendant, traits::CommonGetters,
-  user_config::UserConfig, wm_state::WmState,
+  commands::{
+    container::set_focused_descendant, window::move_window_to_workspace,
+    workspace::focus_workspace,
+  },
+  models::WorkspaceTarget,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
 };
 
 pub fn handle_mouse_move(
@@ -11,12 +23,19 @@ pub fn handle_mouse_move(
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<()> {
+  if event.is_mouse_down
+    && config.value.window_behavior.drag.edge_dwell_px > 0
+  {
+    update_edge_dwell(&event.point, state, config)?;
+  }
+
   // Ignore event if left/right-click is down. Otherwise, this causes focus
   // to jitter when a window is being resized by its drag handles.
   // Also ignore if the OS focused window isn't the same as the WM's
-  // focused window.
+  // focused window, or while the cursor follows a keyboard grab.
   if event.is_mouse_down
     || !state.is_focus_synced
+    || state.keyboard_grab.is_some()
     || !config.value.general.focus_follows_cursor
   {
     return Ok(());
@@ -56,3 +75,129 @@ pub fn handle_mouse_move(
 
   Ok(())
 }
+
+/// Handles a scheduled edge dwell check. The cursor might have been held
+/// still at the screen edge since the dwell started, in which case no
+/// mouse move events arrive to complete it.
+pub fn handle_edge_dwell_elapsed(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if state.is_paused
+    || config.value.window_behavior.drag.edge_dwell_px <= 0
+  {
+    return Ok(());
+  }
+
+  update_edge_dwell(&Platform::mouse_position()?, state, config)
+}
+
+/// Moves the window that's being dragged to the previous or next workspace
+/// once the cursor has been held at the left or right edge of the screen
+/// for long enough.
+///
+/// Only outer edges count, such that dragging between monitors is
+/// unaffected. The dragged window's workspace gets focused, so that the
+/// drag can continue on the new workspace.
+fn update_edge_dwell(
+  point: &Point,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let drag_config = &config.value.window_behavior.drag;
+
+  let Some((window, active_drag)) =
+    state.windows().into_iter().find_map(|window| {
+      let active_drag = window.active_drag().filter(|active_drag| {
+        active_drag.operation == Some(ActiveDragOperation::Moving)
+      })?;
+
+      Some((window, active_drag))
+    })
+  else {
+    return Ok(());
+  };
+
+  let Some(monitor) = state.monitor_at_point(point) else {
+    return Ok(());
+  };
+
+  let monitor_rect = monitor.to_rect()?;
+
+  let edge = if point.x < monitor_rect.left + drag_config.edge_dwell_px {
+    Some(Direction::Left)
+  } else if point.x >= monitor_rect.right - drag_config.edge_dwell_px {
+    Some(Direction::Right)
+  } else {
+    None
+  };
+
+  // Ignore edges that border another monitor.
+  let edge = match edge {
+    Some(edge)
+      if state.monitor_in_direction(&monitor, &edge)?.is_none() =>
+    {
+      Some(edge)
+    }
+    _ => None,
+  };
+
+  let dwell_duration = Duration::from_millis(drag_config.edge_dwell_ms);
+
+  let edge_dwell = match (edge, active_drag.edge_dwell.clone()) {
+    (Some(edge), Some(edge_dwell)) if edge_dwell.direction == edge => {
+      if edge_dwell.since.elapsed() < dwell_duration {
+        return Ok(());
+      }
+
+      info!("Dragged window held at screen edge: {window}");
+
+      let target = match edge {
+        Direction::Left => WorkspaceTarget::Previous,
+        _ => WorkspaceTarget::Next,
+      };
+
+      move_window_to_workspace(window.clone(), target, state, config)?;
+
+      let workspace = window.workspace().context("No workspace.")?;
+
+      focus_workspace(
+        WorkspaceTarget::Name(workspace.config().name),
+        state,
+        config,
+      )?;
+
+      set_focused_descendant(&window.clone().into(), None);
+
+      // The window is positioned by the drag, so avoid redrawing it.
+      state
+        .pending_sync
+        .dequeue_container_from_redraw(window.clone());
+
+      // Restart the dwell, such that holding at the edge keeps moving the
+      // window along.
+      Some(DragEdgeDwell {
+        direction: edge,
+        since: Instant::now(),
+      })
+    }
+    (Some(edge), _) => Some(DragEdgeDwell {
+      direction: edge,
+      since: Instant::now(),
+    }),
+    (None, _) => None,
+  };
+
+  // Check the started dwell again once it's due. The timer is started
+  // after `since` is recorded, so it never fires early.
+  if edge_dwell.is_some() {
+    state.schedule_edge_dwell_check(dwell_duration);
+  }
+
+  window.set_active_drag(Some(ActiveDrag {
+    edge_dwell,
+    ..active_drag
+  }));
+
+  Ok(())
+}
@@ -0,0 +1,142 @@
+use anyhow::Context;
+use wm_platform::{MouseWheelEvent, NativeWindow, Platform};
+
+use crate::{
+  commands::{
+    container::set_focused_descendant, workspace::focus_workspace,
+  },
+  models::WorkspaceTarget,
+  traits::CommonGetters,
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Handles the event for when the mouse wheel is scrolled.
+///
+/// If enabled in the user config, scrolling over an empty area of a
+/// monitor cycles through the monitor's active workspaces. Scrolls over a
+/// window are left for the window to handle.
+pub fn handle_mouse_wheel(
+  event: &MouseWheelEvent,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !config.value.general.switch_workspace_on_scroll || event.delta == 0 {
+    return Ok(());
+  }
+
+  let hit_window = Platform::window_from_point(&event.point)
+    .and_then(|window| Platform::root_ancestor(&window))
+    .ok();
+
+  switch_workspace_on_scroll(event, hit_window.as_ref(), state, config)
+}
+
+/// Cycles through the active workspaces of the monitor under the cursor,
+/// if the scroll is over a gap rather than a window.
+///
+/// The hit window is the root native window under the cursor.
+fn switch_workspace_on_scroll(
+  event: &MouseWheelEvent,
+  hit_window: Option<&NativeWindow>,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !state.is_point_over_gap(&event.point, hit_window) {
+    return Ok(());
+  }
+
+  let cursor_monitor = state
+    .monitor_at_point(&event.point)
+    .context("No monitor under cursor.")?;
+
+  let focused_monitor = state
+    .focused_container()
+    .context("No focused container.")?
+    .monitor()
+    .context("Focused container has no monitor.")?;
+
+  // Focus the monitor under the cursor, so that the workspace switch
+  // applies to that monitor.
+  if cursor_monitor.id() != focused_monitor.id() {
+    set_focused_descendant(&cursor_monitor.as_container(), None);
+    state.pending_sync.queue_focus_change();
+  }
+
+  // Scrolling up goes to the previous workspace and scrolling down goes
+  // to the next.
+  let target = if event.delta > 0 {
+    WorkspaceTarget::PreviousActiveInMonitor
+  } else {
+    WorkspaceTarget::NextActiveInMonitor
+  };
+
+  focus_workspace(target, state, config)
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{ParsedConfig, Point, TilingDirection};
+
+  use super::*;
+  use crate::{
+    models::Workspace,
+    test_utils::{attach, tiling_window, wm_state, workspace},
+  };
+
+  fn focused_workspace(state: &WmState) -> Option<Workspace> {
+    state
+      .focused_container()
+      .and_then(|focused| focused.workspace())
+  }
+
+  #[test]
+  fn switches_workspace_only_over_gap() {
+    let workspace_1 = workspace(TilingDirection::Horizontal);
+    let workspace_2 = workspace(TilingDirection::Horizontal);
+
+    // Windows keep the workspaces from being destroyed when unfocused.
+    attach(tiling_window(1), workspace_1.clone());
+    attach(tiling_window(2), workspace_2.clone());
+
+    let mut state = wm_state(&workspace_1);
+    let monitor = workspace_1.monitor().expect("No monitor.");
+    attach(workspace_2.clone(), monitor);
+
+    let config = UserConfig::from_value(ParsedConfig::default());
+    let event = MouseWheelEvent {
+      point: Point { x: 500, y: 500 },
+      delta: -120,
+    };
+
+    let app_window = NativeWindow::with_names(10, "app.exe", "AppWindow");
+    let desktop_window =
+      NativeWindow::with_names(11, "explorer.exe", "WorkerW");
+
+    switch_workspace_on_scroll(
+      &event,
+      Some(&app_window),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(
+      focused_workspace(&state).map(|workspace| workspace.id()),
+      Some(workspace_1.id())
+    );
+
+    switch_workspace_on_scroll(
+      &event,
+      Some(&desktop_window),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(
+      focused_workspace(&state).map(|workspace| workspace.id()),
+      Some(workspace_2.id())
+    );
+  }
+}
@@ -174,21 +174,26 @@ fn update_drag_state(
   config: &UserConfig,
 ) -> anyhow::Result<()> {
   if let Some(active_drag) = window.active_drag() {
-    let should_ignore = active_drag.operation.is_some()
+    let should_ignore = active_drag.operation
+      == Some(ActiveDragOperation::Resizing)
       || frame_position == old_frame_position;
 
     if should_ignore {
       return Ok(());
     }
 
-    let is_move = frame_position.height() == old_frame_position.height()
-      && frame_position.width() == old_frame_position.width();
+    // The operation is usually known from when the drag was started.
+    // Otherwise, infer it from whether the window's size has changed.
+    let operation = active_drag.operation.unwrap_or_else(|| {
+      let is_move = frame_position.height() == old_frame_position.height()
+        && frame_position.width() == old_frame_position.width();
 
-    let operation = if is_move {
-      ActiveDragOperation::Moving
-    } else {
-      ActiveDragOperation::Resizing
-    };
+      if is_move {
+        ActiveDragOperation::Moving
+      } else {
+        ActiveDragOperation::Resizing
+      }
+    });
 
     window.set_active_drag(Some(ActiveDrag {
       operation: Some(operation),
@@ -196,7 +201,7 @@ fn update_drag_state(
     }));
 
     // Transition window to be floating while it's being dragged.
-    if is_move {
+    if operation == ActiveDragOperation::Moving {
       let parent = window.parent().context("No parent")?;
 
       let window = update_window_state(
@@ -0,0 +1,158 @@
+use tracing::info;
+use wm_common::{try_warn, ActiveDragOperation, DragResult, WmEvent};
+use wm_platform::NativeWindow;
+
+use crate::{
+  events::{handle_window_moved_or_resized_end, restore_to_tiling_origin},
+  models::WindowContainer,
+  traits::{CommonGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Handles the event for when a window move or resize is canceled by the
+/// user (e.g. by pressing escape mid-drag).
+///
+/// If enabled in the user config, the window is restored to its pre-drag
+/// position instead of being dropped. Otherwise, this is handled the same
+/// as a regular drag end.
+pub fn handle_window_moved_or_resized_canceled(
+  native_window: &NativeWindow,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !config.value.window_behavior.drag.restore_on_cancel {
+    return handle_window_moved_or_resized_end(
+      native_window,
+      state,
+      config,
+    );
+  }
+
+  // Don't update state on resize events if the WM is paused.
+  if state.is_paused {
+    return Ok(());
+  }
+
+  let found_window = state.window_from_native(native_window);
+
+  if let Some(window) = found_window {
+    info!("Window move/resize canceled: {window}");
+
+    // The OS reverts the window's frame on cancel, so the cached position
+    // needs to be refreshed.
+    try_warn!(window.native().refresh_frame_position());
+
+    let window = match &window {
+      WindowContainer::NonTilingWindow(non_tiling_window) => {
+        let is_tiling_move =
+          non_tiling_window.active_drag().is_some_and(|active_drag| {
+            active_drag.is_from_tiling
+              && active_drag.operation == Some(ActiveDragOperation::Moving)
+          });
+
+        // Restore the window to the slot it was picked up from, even if
+        // it has since been dragged to another monitor.
+        if is_tiling_move {
+          restore_to_tiling_origin(non_tiling_window, state, config)?
+        } else {
+          window
+        }
+      }
+      WindowContainer::TilingWindow(_) => {
+        // Tiling windows that were being resized are redrawn to their
+        // original size, skipping the resize that would happen on drop.
+        state.pending_sync.queue_container_to_redraw(window.clone());
+        window
+      }
+    };
+
+    let is_dragging = window.active_drag().is_some();
+    window.set_active_drag(None);
+    state.layout_history.discard(window.id());
+
+    if is_dragging {
+      state.emit_event(WmEvent::DragEnded {
+        window_id: window.id(),
+        result: DragResult::SnappedBack,
+      });
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{
+    ActiveDrag, FloatingStateConfig, ParsedConfig, TilingDirection,
+    WindowState,
+  };
+
+  use super::*;
+  use crate::{
+    commands::window::update_window_state,
+    events::handle_window_moved_or_resized_start,
+    test_utils::{attach, split, tiling_window, wm_state, workspace},
+  };
+
+  #[test]
+  fn restores_window_to_tiling_origin_on_cancel() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let split = split(TilingDirection::Vertical);
+    let window = tiling_window(2);
+
+    attach(tiling_window(1), workspace.clone());
+    attach(split.clone(), workspace.clone());
+    attach(window.clone(), split.clone());
+    attach(tiling_window(3), split.clone());
+
+    let mut state = wm_state(&workspace);
+    let mut config = UserConfig::from_value(ParsedConfig::default());
+    config.value.window_behavior.drag.restore_on_cancel = true;
+
+    let native_window = window.native().clone();
+    handle_window_moved_or_resized_start(&native_window, &mut state);
+
+    // The window is made floating once it's moved, same as on a location
+    // change mid-drag.
+    let active_drag = window.active_drag().expect("No active drag.");
+    window.set_active_drag(Some(ActiveDrag {
+      operation: Some(ActiveDragOperation::Moving),
+      ..active_drag
+    }));
+
+    let floating_window = update_window_state(
+      window.into(),
+      WindowState::Floating(FloatingStateConfig::default()),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(
+      floating_window.parent().map(|parent| parent.id()),
+      Some(workspace.id())
+    );
+
+    // E.g. escape was pressed mid-drag.
+    handle_window_moved_or_resized_canceled(
+      &native_window,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    let restored_window = state
+      .window_from_native(&native_window)
+      .expect("No restored window.");
+
+    assert!(restored_window.is_tiling_window());
+    assert!(restored_window.active_drag().is_none());
+    assert_eq!(
+      restored_window.parent().map(|parent| parent.id()),
+      Some(split.id())
+    );
+    assert_eq!(restored_window.index(), 0);
+  }
+}
@@ -1,22 +1,37 @@
+use std::{
+  cmp::Ordering,
+  time::{Duration, Instant},
+};
+
 use anyhow::Context;
 use tracing::info;
+use uuid::Uuid;
 use wm_common::{
-  try_warn, ActiveDragOperation, LengthValue, Point, Rect,
-  TilingDirection, WindowState,
+  desired_split_direction, try_warn, ActiveDrag, ActiveDragOperation,
+  CursorJumpAnchor, CursorJumpConfig, CursorJumpTrigger, DragResult,
+  DropAnchor, DropOnFullscreen, DropPlan, DropPosition, LengthValue,
+  Point, Rect, TilingDirection, WindowState, WmEvent,
 };
 use wm_platform::{NativeWindow, Platform};
 
 use crate::{
   commands::{
-    container::{move_container_within_tree, wrap_in_split_container},
-    window::{resize_window, update_window_state},
+    container::{
+      flatten_redundant_split_containers, move_container_within_tree,
+      set_focused_descendant, swap_containers, wrap_in_split_container,
+    },
+    window::{
+      move_window_to_workspace, resize_window, update_window_state,
+    },
   },
   models::{
-    DirectionContainer, NonTilingWindow, SplitContainer, TilingContainer,
-    WindowContainer,
+    Container, DirectionContainer, Monitor, NonTilingWindow,
+    SplitContainer, TilingContainer, WindowContainer, Workspace,
+    WorkspaceTarget,
   },
   traits::{
-    CommonGetters, PositionGetters, TilingDirectionGetters, WindowGetters,
+    CommonGetters, PositionGetters, TilingDirectionGetters,
+    TilingSizeGetters, WindowGetters,
   },
   user_config::UserConfig,
   wm_state::WmState,
@@ -40,115 +55,609 @@ pub fn handle_window_moved_or_resized_end(
   let found_window = state.window_from_native(native_window);
 
   if let Some(window) = found_window {
-    info!("Window move/resize ended: {window}");
-
     let new_rect = try_warn!(window.native().refresh_frame_position());
-    let old_rect = window.to_rect()?;
+    let mouse_pos = Platform::mouse_position().ok();
+
+    end_move_or_resize(
+      &window,
+      new_rect,
+      mouse_pos.as_ref(),
+      state,
+      config,
+    )?;
+  }
+
+  Ok(())
+}
+
+/// Handles the end of a window move or resize, given the window's frame
+/// after the drag and the cursor position (if it could be retrieved).
+///
+/// Repeated end events for the same drag are ignored.
+#[allow(clippy::too_many_lines)]
+fn end_move_or_resize(
+  window: &WindowContainer,
+  new_rect: Rect,
+  mouse_pos: Option<&Point>,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if is_repeated_drag_end(window, state, config) {
+    info!("Ignoring repeated move/resize end: {window}");
+    return Ok(());
+  }
+
+  info!("Window move/resize ended: {window}");
+
+  // Aspect ratio locked windows are drawn smaller than their tile, so
+  // compare against the drawn size when calculating the resize delta.
+  let old_rect = match (window.state(), window.aspect_ratio_lock()) {
+    (WindowState::Tiling, Some(aspect_ratio)) => {
+      window.to_rect()?.fit_aspect_ratio(aspect_ratio)
+    }
+    _ => window.to_rect()?,
+  };
+
+  let old_monitor_id = window.monitor().map(|monitor| monitor.id());
+
+  let width_delta = new_rect.width() - old_rect.width();
+  let height_delta = new_rect.height() - old_rect.height();
+
+  let is_dragging = window.active_drag().is_some();
+
+  // The window gets moved to the workspace under the cursor mid-drag,
+  // so its current monitor can't tell whether the drag changed
+  // monitors.
+  let start_monitor_id = window
+    .active_drag()
+    .and_then(|active_drag| active_drag.start_monitor_id);
 
-    let width_delta = new_rect.width() - old_rect.width();
-    let height_delta = new_rect.height() - old_rect.height();
+  if is_dragging {
+    relocate_from_removed_monitor(window, start_monitor_id, state)?;
+  }
 
-    match &window {
-      WindowContainer::NonTilingWindow(window) => {
-        if let Some(active_drag) = window.active_drag() {
+  let drag_result = match window {
+    WindowContainer::NonTilingWindow(window) => {
+      match window.active_drag() {
+        Some(active_drag)
           if active_drag.is_from_tiling
-            && active_drag.operation == Some(ActiveDragOperation::Moving)
-          {
-            // Window is a temporary floating window that should be
-            // reverted back to tiling.
-            drop_as_tiling_window(window, state, config)?;
+            && active_drag.operation
+              == Some(ActiveDragOperation::Moving) =>
+        {
+          // Window is a temporary floating window that should be
+          // reverted back to tiling.
+          if is_within_drag_threshold(&active_drag, mouse_pos, config) {
+            restore_to_tiling_origin(window, state, config)?;
+            DragResult::SnappedBack
+          } else {
+            let mouse_pos = mouse_pos.context("No mouse position.")?;
+            drop_as_tiling_window(window, mouse_pos, state, config)?.0
           }
         }
-      }
-      WindowContainer::TilingWindow(window) => {
-        let parent = window.parent().context("No parent.")?;
-
-        // Snap window to its original position if it's the only window in
-        // the workspace.
-        if parent.is_workspace() && window.tiling_siblings().count() == 0 {
-          state.pending_sync.queue_container_to_redraw(window.clone());
-          return Ok(());
+        Some(active_drag)
+          if active_drag.operation
+            == Some(ActiveDragOperation::Moving)
+            && matches!(window.state(), WindowState::Floating(_)) =>
+        {
+          snap_floating_window(window, &new_rect, state, config)?;
+          DragResult::Floated
         }
+        _ => DragResult::Floated,
+      }
+    }
+    WindowContainer::TilingWindow(window) => {
+      let workspace = window.workspace().context("No workspace.")?;
 
+      // Snap window to its original position if it's the only tiling
+      // window in the workspace. The window might be nested in redundant
+      // split containers, so the whole workspace is checked rather than
+      // just its siblings.
+      let tiling_window_count = workspace
+        .descendants()
+        .filter(|descendant| {
+          matches!(descendant, Container::TilingWindow(_))
+        })
+        .count();
+
+      if tiling_window_count == 1 {
+        state.pending_sync.queue_container_to_redraw(window.clone());
+        DragResult::SnappedBack
+      } else {
         resize_window(
           &window.clone().into(),
           Some(LengthValue::from_px(width_delta)),
           Some(LengthValue::from_px(height_delta)),
           state,
+          config,
         )?;
+
+        DragResult::Tiled
       }
     }
+  };
+
+  // The window container might have been replaced (e.g. when a floating
+  // window is dropped as tiling), so the drag is cleared on both.
+  window.set_active_drag(None);
+
+  if let Some(new_window) = state
+    .container_by_id(window.id())
+    .and_then(|container| container.as_window_container().ok())
+  {
+    new_window.set_active_drag(None);
+  }
+
+  // Tiling drops and resizes can be undone.
+  if drag_result == DragResult::Tiled {
+    state
+      .layout_history
+      .commit(window.id(), config.value.general.undo_limit);
+  } else {
+    state.layout_history.discard(window.id());
+  }
+
+  // Look up the window again by its ID in case it was replaced.
+  let new_monitor_id = state
+    .container_by_id(window.id())
+    .and_then(|container| container.monitor())
+    .map(|monitor| monitor.id());
+
+  let monitor_changed = old_monitor_id != new_monitor_id;
+
+  let drag_monitor_changed =
+    start_monitor_id.is_some() && start_monitor_id != new_monitor_id;
+
+  // Keep the cursor over the window after it settles on the new
+  // monitor.
+  if drag_result == DragResult::Tiled
+    && drag_monitor_changed
+    && config
+      .value
+      .window_behavior
+      .drag
+      .recenter_cursor_on_monitor_change
+  {
+    state.pending_sync.queue_cursor_jump_with(CursorJumpConfig {
+      enabled: true,
+      trigger: CursorJumpTrigger::WindowFocus,
+      anchor: CursorJumpAnchor::Center,
+    });
+  }
+
+  state.emit_event(WmEvent::WindowMovedOrResizedEnded {
+    window_id: window.id(),
+    old_rect,
+    new_rect,
+    monitor_changed,
+  });
+
+  if is_dragging {
+    state.emit_event(WmEvent::DragEnded {
+      window_id: window.id(),
+      result: drag_ended_result(drag_result, drag_monitor_changed),
+    });
+  }
+  Ok(())
+}
 
-    window.set_active_drag(None);
+/// Gets the result to report for an ended drag. A drag that changed the
+/// window's monitor is reported as such, unless the window snapped back.
+fn drag_ended_result(
+  drag_result: DragResult,
+  is_monitor_change: bool,
+) -> DragResult {
+  match drag_result {
+    DragResult::SnappedBack => DragResult::SnappedBack,
+    _ if is_monitor_change => DragResult::MovedMonitor,
+    _ => drag_result,
   }
+}
+
+/// Moves the dragged window to the displayed workspace of the primary
+/// monitor if the monitor that the drag started on was removed mid-drag.
+///
+/// The window's workspace gets moved to another monitor when its monitor
+/// is removed, but without being displayed there. Windows that are
+/// already on a displayed workspace are left as-is.
+fn relocate_from_removed_monitor(
+  window: &WindowContainer,
+  start_monitor_id: Option<Uuid>,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let monitors = state.monitors();
+
+  let is_start_monitor_removed = start_monitor_id
+    .is_some_and(|id| !monitors.iter().any(|monitor| monitor.id() == id));
+
+  let workspace = window.workspace().context("No workspace.")?;
+
+  if !is_start_monitor_removed || workspace.is_displayed() {
+    return Ok(());
+  }
+
+  let target_workspace = monitors
+    .iter()
+    .find(|monitor| monitor.native().is_primary().unwrap_or(false))
+    .or_else(|| monitors.first())
+    .and_then(Monitor::displayed_workspace)
+    .context("No displayed workspace.")?;
+
+  info!(
+    "Dragged window's monitor was removed. Moving to workspace: {target_workspace}",
+  );
+
+  move_container_within_tree(
+    &window.clone().into(),
+    &target_workspace.clone().into(),
+    target_workspace.child_count(),
+    state,
+  )?;
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(window.clone())
+    .queue_containers_to_redraw(workspace.tiling_children());
 
   Ok(())
 }
 
+/// Whether the cursor has moved less than the configured threshold since
+/// the drag was started.
+fn is_within_drag_threshold(
+  active_drag: &ActiveDrag,
+  mouse_pos: Option<&Point>,
+  config: &UserConfig,
+) -> bool {
+  let threshold = config.value.window_behavior.drag.tiling_threshold_px;
+
+  let Some(start_position) = &active_drag.start_position else {
+    return false;
+  };
+
+  mouse_pos.is_some_and(|mouse_pos| {
+    mouse_pos.distance_to(start_position) < f64::from(threshold)
+  })
+}
+
+/// Gets the tiling child of the given container that's nearest to the
+/// point.
+///
+/// See `nearest_rect_index` for how the child is picked.
+fn nearest_tiling_child(
+  parent: &DirectionContainer,
+  point: &Point,
+) -> anyhow::Result<Option<TilingContainer>> {
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+
+  let indexed_rects = tiling_children
+    .iter()
+    .map(|child| anyhow::Ok((child.index(), child.to_rect()?)))
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
+  let nearest_child =
+    nearest_rect_index(&indexed_rects, point).and_then(|index| {
+      tiling_children
+        .into_iter()
+        .find(|child| child.index() == index)
+    });
+
+  Ok(nearest_child)
+}
+
+/// Gets the index of the rect that's nearest to the point, from pairs of
+/// child index and rect.
+///
+/// A rect that contains the point is always preferred. Otherwise, the
+/// nearest rect by distance is used. Equidistant rects are resolved by
+/// their index (lowest wins), so that the result is deterministic.
+fn nearest_rect_index(
+  indexed_rects: &[(usize, Rect)],
+  point: &Point,
+) -> Option<usize> {
+  indexed_rects
+    .iter()
+    .min_by(|(index_a, rect_a), (index_b, rect_b)| {
+      // Sort rects that contain the point first.
+      rect_b
+        .contains_point(point)
+        .cmp(&rect_a.contains_point(point))
+        .then_with(|| {
+          rect_a
+            .distance_to_point(point)
+            .total_cmp(&rect_b.distance_to_point(point))
+        })
+        .then_with(|| index_a.cmp(index_b))
+    })
+    .map(|(index, _)| *index)
+}
+
+/// Snaps a floating window flush to the edges of its monitor and of other
+/// floating windows in its workspace, if within the configured snap
+/// distance.
+///
+/// Each axis is snapped independently, such that the window can snap to
+/// a corner. The window is kept within the monitor's working area.
+fn snap_floating_window(
+  window: &NonTilingWindow,
+  window_rect: &Rect,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let snap_distance =
+    config.value.window_behavior.floating.snap_distance_px;
+
+  if snap_distance <= 0 {
+    return Ok(());
+  }
+
+  let workspace = window.workspace().context("No workspace.")?;
+  let monitor = workspace.monitor().context("No monitor.")?;
+  let monitor_rect = monitor.native().working_rect()?.clone();
+
+  let sibling_rects = workspace
+    .descendants()
+    .filter_map(|descendant| descendant.as_non_tiling_window().cloned())
+    .filter(|sibling| {
+      sibling.id() != window.id()
+        && matches!(sibling.state(), WindowState::Floating(_))
+    })
+    .filter_map(|sibling| sibling.to_rect().ok())
+    .collect::<Vec<_>>();
+
+  // Edges that the window's left/right and top/bottom edges can snap to.
+  // Sibling edges are only included if the sibling is close enough on the
+  // other axis.
+  let mut x_edges = vec![monitor_rect.left, monitor_rect.right];
+  let mut y_edges = vec![monitor_rect.top, monitor_rect.bottom];
+
+  for sibling_rect in &sibling_rects {
+    let is_near_y = sibling_rect.top - snap_distance <= window_rect.bottom
+      && window_rect.top <= sibling_rect.bottom + snap_distance;
+
+    let is_near_x = sibling_rect.left - snap_distance <= window_rect.right
+      && window_rect.left <= sibling_rect.right + snap_distance;
+
+    if is_near_y {
+      x_edges.extend([sibling_rect.left, sibling_rect.right]);
+    }
+
+    if is_near_x {
+      y_edges.extend([sibling_rect.top, sibling_rect.bottom]);
+    }
+  }
+
+  let snap_offset = |start: i32, end: i32, edges: &[i32]| {
+    edges
+      .iter()
+      .flat_map(|edge| [edge - start, edge - end])
+      .filter(|offset| offset.abs() <= snap_distance)
+      .min_by_key(|offset| offset.abs())
+      .unwrap_or(0)
+  };
+
+  let offset_x =
+    snap_offset(window_rect.left, window_rect.right, &x_edges);
+  let offset_y =
+    snap_offset(window_rect.top, window_rect.bottom, &y_edges);
+
+  if offset_x == 0 && offset_y == 0 {
+    return Ok(());
+  }
+
+  // Prevent snapping from pushing the window off-screen.
+  let snapped_x = (window_rect.left + offset_x).clamp(
+    monitor_rect.left,
+    (monitor_rect.right - window_rect.width()).max(monitor_rect.left),
+  );
+
+  let snapped_y = (window_rect.top + offset_y).clamp(
+    monitor_rect.top,
+    (monitor_rect.bottom - window_rect.height()).max(monitor_rect.top),
+  );
+
+  window.set_floating_placement(
+    window_rect.translate_to_coordinates(snapped_x, snapped_y),
+  );
+
+  state.pending_sync.queue_container_to_redraw(window.clone());
+
+  Ok(())
+}
+
+/// Whether an end event was already handled for the window within the
+/// debounce time. Otherwise, records the current event as handled.
+///
+/// The window's entry is cleared when a new drag is started, so separate
+/// drags of the same window aren't affected.
+fn is_repeated_drag_end(
+  window: &WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> bool {
+  let debounce = Duration::from_millis(
+    config.value.window_behavior.drag.end_debounce_ms,
+  );
+
+  if state
+    .drag_end_timestamps
+    .get(&window.id())
+    .is_some_and(|timestamp| timestamp.elapsed() < debounce)
+  {
+    return true;
+  }
+
+  state
+    .drag_end_timestamps
+    .retain(|_, timestamp| timestamp.elapsed() < debounce);
+  state
+    .drag_end_timestamps
+    .insert(window.id(), Instant::now());
+
+  false
+}
+
 /// Handles transition from temporary floating window to tiling window on
 /// drag end.
-fn drop_as_tiling_window(
+///
+/// The window is placed relative to the container under the given anchor
+/// point, which is the cursor position for mouse drags.
+///
+/// Returns the drag result along with the window after the drop. The
+/// result is `DragResult::SnappedBack` if the window was restored to
+/// where it was picked up from (i.e. the drop was ignored or was back
+/// onto its original slot), otherwise `DragResult::Tiled`.
+#[allow(clippy::too_many_lines)]
+pub fn drop_as_tiling_window(
   moved_window: &NonTilingWindow,
+  mouse_pos: &Point,
   state: &mut WmState,
   config: &UserConfig,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<(DragResult, WindowContainer)> {
   info!(
     "Tiling window drag ended: {}",
     moved_window.as_window_container()?
   );
 
-  let mouse_pos = Platform::mouse_position()?;
-  let workspace = moved_window.workspace().context("No workspace.")?;
+  // A matching drop rule takes precedence over the cursor position and
+  // the `anchor` setting.
+  let drop_rule = config.drop_rule(&moved_window.clone().into())?;
+  let rule_workspace = drop_rule.and_then(|rule| rule.workspace.clone());
+  let rule_position = drop_rule.and_then(|rule| rule.position.clone());
+  let is_workspace_forced = rule_workspace.is_some();
 
-  // Get the workspace, split containers, and other windows under the
-  // dragged window.
-  let containers_at_pos = state
-    .containers_at_point(&workspace.clone().into(), &mouse_pos)
-    .into_iter()
-    .filter(|container| container.id() != moved_window.id());
+  // Dropping onto a workspace with a fullscreen window would otherwise
+  // place the window underneath it.
+  let fullscreen_window = drop_target_workspace(
+    &moved_window.clone().into(),
+    rule_workspace.as_ref(),
+    mouse_pos,
+    state,
+  )
+  .and_then(|workspace| workspace.focused_fullscreen_window())
+  .filter(|fullscreen_window| fullscreen_window.id() != moved_window.id());
 
-  // Get the deepest direction container under the dragged window.
-  let target_parent: DirectionContainer = containers_at_pos
-    .filter_map(|container| container.as_direction_container().ok())
-    .fold(workspace.into(), |acc, container| {
-      if container.ancestors().count() > acc.ancestors().count() {
-        container
-      } else {
-        acc
+  if let Some(fullscreen_window) = fullscreen_window {
+    match config.value.window_behavior.drop.on_fullscreen {
+      DropOnFullscreen::Ignore => {
+        info!("Ignoring drop onto fullscreen window.");
+
+        let window =
+          restore_to_tiling_origin(moved_window, state, config)?;
+        return Ok((DragResult::SnappedBack, window));
+      }
+      DropOnFullscreen::Unfullscreen => {
+        let target_state = fullscreen_window
+          .toggled_state(fullscreen_window.state(), config);
+
+        update_window_state(
+          fullscreen_window.into(),
+          target_state,
+          state,
+          config,
+        )?;
       }
+    }
+  }
+
+  let workspace = match rule_workspace {
+    Some(workspace_name) => {
+      move_window_to_workspace(
+        moved_window.clone().into(),
+        WorkspaceTarget::Name(workspace_name),
+        state,
+        config,
+      )?;
+
+      moved_window.workspace().context("No workspace.")?
+    }
+    None => {
+      try_arrange_window_between_monitors(moved_window, mouse_pos, state)?
+    }
+  };
+
+  // Windows only stay sticky while floating.
+  moved_window.set_sticky(false);
+
+  let target_parent = drop_target_parent(
+    &moved_window.clone().into(),
+    &workspace,
+    mouse_pos,
+    is_workspace_forced,
+    state,
+  );
+
+  // Restore the window to its original position if it's dropped back
+  // onto where it was picked up from.
+  let tiling_origin = moved_window
+    .active_drag()
+    .and_then(|active_drag| active_drag.tiling_origin)
+    .filter(|origin| {
+      !is_workspace_forced
+        && origin.parent_id == target_parent.id()
+        && origin.rect.contains_point(mouse_pos)
     });
 
+  if tiling_origin.is_some() {
+    let window = restore_to_tiling_origin(moved_window, state, config)?;
+    return Ok((DragResult::SnappedBack, window));
+  }
+
   // If the target parent has no children (i.e. an empty workspace), then
-  // add the window directly.
+  // add the window directly. The drop position relative to the workspace
+  // still sets its tiling direction, so that the next window dropped is
+  // split in the implied direction.
   if target_parent.tiling_children().count() == 0 {
-    update_window_state(
+    let drop_position = match &rule_position {
+      Some(rule_position) => rule_position.clone(),
+      None if is_workspace_forced => DropPosition::Center,
+      None => drop_position(
+        mouse_pos,
+        &target_parent.to_rect()?,
+        &target_parent.tiling_direction(),
+        config.value.window_behavior.drop.center_fraction,
+      ),
+    };
+
+    let tiling_direction = desired_split_direction(
+      &drop_position,
+      &target_parent.tiling_direction(),
+    );
+
+    if target_parent.tiling_direction() != tiling_direction {
+      target_parent.set_tiling_direction(tiling_direction);
+
+      state.emit_event(WmEvent::TilingDirectionChanged {
+        direction_container: target_parent.to_dto()?,
+        new_tiling_direction: target_parent.tiling_direction(),
+      });
+    }
+
+    let window = update_window_state(
       moved_window.clone().into(),
       WindowState::Tiling,
       state,
       config,
     )?;
 
-    return Ok(());
+    return Ok((DragResult::Tiled, window));
   }
 
-  let nearest_container = target_parent
-    .children()
-    .into_iter()
-    .filter_map(|container| container.as_tiling_container().ok())
-    .try_fold(None, |acc: Option<TilingContainer>, container| match acc {
-      Some(acc) => {
-        let is_nearer = acc.to_rect()?.distance_to_point(&mouse_pos)
-          < container.to_rect()?.distance_to_point(&mouse_pos);
+  let plan = compute_drop_plan(
+    &moved_window.clone().into(),
+    mouse_pos,
+    state,
+    config,
+  )?
+  .context("No drop target.")?;
 
-        anyhow::Ok(Some(if is_nearer { acc } else { container }))
-      }
-      None => Ok(Some(container)),
-    })?
-    .context("No nearest container.")?;
+  let target = state
+    .container_by_id(plan.target_id)
+    .context("No drop target.")?
+    .as_tiling_container()?;
 
-  let tiling_direction = target_parent.tiling_direction();
-  let drop_position =
-    drop_position(&mouse_pos, &nearest_container.to_rect()?);
+  // Size of the window while it was floating. Used to size the window
+  // after it's placed in the tree.
+  let floating_rect = moved_window.to_rect()?;
 
   let moved_window = update_window_state(
     moved_window.clone().into(),
@@ -157,78 +666,434 @@ fn drop_as_tiling_window(
     config,
   )?;
 
-  let should_split = nearest_container.is_tiling_window()
-    && match tiling_direction {
-      TilingDirection::Horizontal => {
-        drop_position == DropPosition::Top
-          || drop_position == DropPosition::Bottom
-      }
-      TilingDirection::Vertical => {
-        drop_position == DropPosition::Left
-          || drop_position == DropPosition::Right
-      }
-    };
+  // The window gets reinserted at its pre-drag position on the state
+  // change, so the swap moves the target window into that position.
+  if plan.is_swap {
+    swap_containers(&moved_window.as_tiling_container()?, &target, state)?;
 
-  if should_split {
-    let split_container = SplitContainer::new(
-      tiling_direction.inverse(),
-      config.value.gaps.clone(),
-    );
+    return Ok((DragResult::Tiled, moved_window));
+  }
+
+  if let Some(split_direction) = plan.split_direction {
+    // Use the gaps of the workspace that the window is dropped into, which
+    // might be on a different monitor than where the drag started.
+    let target_workspace =
+      target_parent.workspace().context("No workspace.")?;
+
+    let split_container =
+      SplitContainer::new(split_direction, target_workspace.gaps_config());
 
     wrap_in_split_container(
       &split_container,
       &target_parent.clone().into(),
-      &[nearest_container],
+      std::slice::from_ref(&target),
     )?;
 
-    let target_index = match drop_position {
-      DropPosition::Top | DropPosition::Left => 0,
-      _ => 1,
-    };
-
     move_container_within_tree(
       &moved_window.clone().into(),
       &split_container.into(),
-      target_index,
+      plan.insert_index,
       state,
     )?;
-  } else {
-    let target_index = match drop_position {
-      DropPosition::Top | DropPosition::Left => nearest_container.index(),
-      _ => nearest_container.index() + 1,
-    };
 
+    let new_split_ratio =
+      config.value.window_behavior.drop.new_split_ratio;
+    moved_window
+      .as_tiling_container()?
+      .set_tiling_size(new_split_ratio);
+    target.set_tiling_size(1. - new_split_ratio);
+  } else {
+    // The window's reinsertion on the state change can shift the target's
+    // index, so the plan's index is resolved against the current tree.
     move_container_within_tree(
       &moved_window.clone().into(),
       &target_parent.clone().into(),
-      target_index,
+      target.index() + insert_offset(&plan.position),
       state,
     )?;
   }
 
-  state.pending_sync.queue_container_to_redraw(target_parent);
+  // The target parent can itself get flattened below, in which case it's
+  // detached from the tree. Its parent is redrawn instead.
+  let redraw_container = match target_parent.parent() {
+    Some(parent) if !target_parent.is_workspace() => parent,
+    _ => target_parent.clone().into(),
+  };
 
-  Ok(())
+  // Clean up any split containers left with a single child by the drop.
+  flatten_redundant_split_containers(&target_parent.clone().into())?;
+
+  // Bias the tiling size toward the window's floating dimensions. Sibling
+  // containers are kept from shrinking below the minimum tiling size.
+  if config.value.window_behavior.drop.preserve_floating_size {
+    let tiling_rect = moved_window.to_rect()?;
+
+    resize_window(
+      &moved_window,
+      Some(LengthValue::from_px(
+        floating_rect.width() - tiling_rect.width(),
+      )),
+      Some(LengthValue::from_px(
+        floating_rect.height() - tiling_rect.height(),
+      )),
+      state,
+      config,
+    )?;
+  }
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(redraw_container);
+
+  Ok((DragResult::Tiled, moved_window))
+}
+
+/// Reverts a dragged window back to tiling at the position it was picked
+/// up from.
+///
+/// The window's insertion target normally points to its pre-drag
+/// position, but it's cleared once the window is dragged to another
+/// monitor. The drag's tiling origin is used to move the window back in
+/// that case.
+pub fn restore_to_tiling_origin(
+  window: &NonTilingWindow,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<WindowContainer> {
+  let tiling_origin = window
+    .active_drag()
+    .and_then(|active_drag| active_drag.tiling_origin);
+
+  let window = update_window_state(
+    window.clone().into(),
+    WindowState::Tiling,
+    state,
+    config,
+  )?;
+
+  let Some(tiling_origin) = tiling_origin else {
+    return Ok(window);
+  };
+
+  let Some(origin_parent) = state.container_by_id(tiling_origin.parent_id)
+  else {
+    return Ok(window);
+  };
+
+  let is_at_origin = window
+    .parent()
+    .is_some_and(|parent| parent.id() == origin_parent.id())
+    && window.index() == tiling_origin.index;
+
+  if !is_at_origin {
+    let prev_workspace = window.workspace().context("No workspace.")?;
+
+    move_container_within_tree(
+      &window.clone().into(),
+      &origin_parent,
+      tiling_origin.index.min(origin_parent.child_count()),
+      state,
+    )?;
+
+    state
+      .pending_sync
+      .queue_container_to_redraw(prev_workspace)
+      .queue_container_to_redraw(
+        window.workspace().context("No workspace.")?,
+      );
+  }
+
+  Ok(window)
+}
+
+/// Gets the workspace that a window would be dropped into. This is the
+/// drop rule's workspace if one is given, otherwise the workspace under
+/// the cursor.
+fn drop_target_workspace(
+  window: &WindowContainer,
+  rule_workspace: Option<&String>,
+  point: &Point,
+  state: &WmState,
+) -> Option<Workspace> {
+  match rule_workspace {
+    Some(workspace_name) => state.workspace_by_name(workspace_name),
+    None => state
+      .nearest_monitor_to_point(point)
+      .and_then(|monitor| monitor.displayed_workspace())
+      .or_else(|| window.workspace()),
+  }
+}
+
+/// Computes where the window would be placed if dropped at the given
+/// point, without making any changes to the tree. This is the same
+/// placement that `drop_as_tiling_window` carries out.
+///
+/// The window is expected to be outside of the tiling layout (e.g.
+/// temporarily floating while dragged).
+///
+/// Returns `None` if the target parent has no tiling children to drop
+/// relative to (e.g. an empty workspace), if a drop rule's workspace
+/// isn't active, or if the drop would be ignored because the workspace
+/// has a fullscreen window.
+pub fn compute_drop_plan(
+  window: &WindowContainer,
+  point: &Point,
+  state: &WmState,
+  config: &UserConfig,
+) -> anyhow::Result<Option<DropPlan>> {
+  let drop_rule = config.drop_rule(window)?;
+  let rule_position = drop_rule.and_then(|rule| rule.position.clone());
+  let is_workspace_forced =
+    drop_rule.is_some_and(|rule| rule.workspace.is_some());
+
+  let Some(workspace) = drop_target_workspace(
+    window,
+    drop_rule.and_then(|rule| rule.workspace.as_ref()),
+    point,
+    state,
+  ) else {
+    return Ok(None);
+  };
+
+  let is_fullscreen_ignored =
+    config.value.window_behavior.drop.on_fullscreen
+      == DropOnFullscreen::Ignore
+      && workspace.focused_fullscreen_window().is_some_and(
+        |fullscreen_window| fullscreen_window.id() != window.id(),
+      );
+
+  if is_fullscreen_ignored {
+    return Ok(None);
+  }
+
+  let target_parent = drop_target_parent(
+    window,
+    &workspace,
+    point,
+    is_workspace_forced,
+    state,
+  );
+
+  // Get the window to place the dropped window relative to. This is the
+  // focused window if the anchor is set to `focused` (or the workspace is
+  // forced by a drop rule) and the window is a child of the target
+  // parent.
+  let is_focused_anchor = is_workspace_forced
+    || config.value.window_behavior.drop.anchor == DropAnchor::Focused;
+
+  let focused_container = is_focused_anchor
+    .then(|| {
+      workspace
+        .descendant_focus_order()
+        .filter(|descendant| descendant.id() != window.id())
+        .find(Container::is_tiling_window)
+    })
+    .flatten()
+    .filter(|focused| {
+      focused
+        .parent()
+        .is_some_and(|parent| parent.id() == target_parent.id())
+    })
+    .and_then(|focused| focused.as_tiling_container().ok());
+
+  let target = match focused_container {
+    Some(focused_container) => focused_container,
+    None => match nearest_tiling_child(&target_parent, point)? {
+      Some(nearest_child) => nearest_child,
+      None => return Ok(None),
+    },
+  };
+
+  let tiling_direction = target_parent.tiling_direction();
+
+  // Without an explicit position, a window moved by a drop rule is placed
+  // after the target window.
+  let position = match rule_position {
+    Some(rule_position) => rule_position,
+    None if is_workspace_forced => match tiling_direction {
+      TilingDirection::Horizontal => DropPosition::Right,
+      TilingDirection::Vertical => DropPosition::Bottom,
+    },
+    None => drop_position(
+      point,
+      &target.to_rect()?,
+      &tiling_direction,
+      config.value.window_behavior.drop.center_fraction,
+    ),
+  };
+
+  // Swapping is skipped for windows moved by a drop rule, since it'd
+  // move the target window into the workspace the drag started from.
+  let is_swap = config.value.window_behavior.drop.swap_on_center
+    && !is_workspace_forced
+    && position == DropPosition::Center
+    && target.is_tiling_window();
+
+  let split_direction =
+    desired_split_direction(&position, &tiling_direction);
+
+  let split_direction = (target.is_tiling_window()
+    && split_direction != tiling_direction)
+    .then_some(split_direction);
+
+  // When split, the window is inserted into the new split container
+  // alongside the target.
+  let insert_index = match split_direction {
+    Some(_) => insert_offset(&position),
+    None => target.index() + insert_offset(&position),
+  };
+
+  Ok(Some(DropPlan {
+    target_parent_id: target_parent.id(),
+    target_id: target.id(),
+    position,
+    insert_index,
+    split_direction,
+    is_swap,
+  }))
+}
+
+/// Gets the direction container to drop the window into.
+///
+/// This is the deepest direction container under the point, or if the
+/// workspace is forced by a drop rule, the parent of the workspace's
+/// focused window.
+fn drop_target_parent(
+  window: &WindowContainer,
+  workspace: &Workspace,
+  point: &Point,
+  is_workspace_forced: bool,
+  state: &WmState,
+) -> DirectionContainer {
+  if is_workspace_forced {
+    // The cursor isn't necessarily over the rule's workspace, so the
+    // window is instead placed beside the workspace's focused window.
+    return workspace
+      .descendant_focus_order()
+      .filter(|descendant| descendant.id() != window.id())
+      .find(Container::is_tiling_window)
+      .and_then(|focused| focused.parent())
+      .and_then(|parent| parent.as_direction_container().ok())
+      .unwrap_or_else(|| workspace.clone().into());
+  }
+
+  // Get the workspace, split containers, and other windows under the
+  // dragged window.
+  let containers_at_pos = state
+    .containers_at_point(&workspace.clone().into(), point)
+    .into_iter()
+    .filter(|container| container.id() != window.id());
+
+  // Get the deepest direction container under the dragged window.
+  containers_at_pos
+    .filter_map(|container| container.as_direction_container().ok())
+    .fold(workspace.clone().into(), |acc, container| {
+      if container.ancestors().count() > acc.ancestors().count() {
+        container
+      } else {
+        acc
+      }
+    })
+}
+
+/// Gets the offset from the target's index at which a window dropped at
+/// the given position is inserted.
+fn insert_offset(position: &DropPosition) -> usize {
+  match position {
+    DropPosition::Top | DropPosition::Left => 0,
+    _ => 1,
+  }
 }
 
-/// Represents where the window was dropped over another.
-#[derive(Debug, Clone, PartialEq)]
-enum DropPosition {
-  Top,
-  Bottom,
-  Left,
-  Right,
+/// Moves the dragged window to the displayed workspace of the monitor
+/// under the cursor, if it differs from the window's current workspace.
+///
+/// Prefers the monitor that contains the cursor, but falls back to the
+/// nearest monitor if the cursor is outside of all monitors (e.g. in the
+/// dead corner of an L-shaped monitor arrangement).
+///
+/// Returns the window's workspace after the move.
+fn try_arrange_window_between_monitors(
+  moved_window: &NonTilingWindow,
+  mouse_pos: &Point,
+  state: &mut WmState,
+) -> anyhow::Result<Workspace> {
+  let workspace = moved_window.workspace().context("No workspace.")?;
+
+  // Sticky windows stay on their monitor unless explicitly dragged.
+  if moved_window.is_sticky() && moved_window.active_drag().is_none() {
+    return Ok(workspace);
+  }
+
+  let target_workspace = state
+    .nearest_monitor_to_point(mouse_pos)
+    .and_then(|monitor| monitor.displayed_workspace());
+
+  match target_workspace {
+    Some(target_workspace) if target_workspace.id() != workspace.id() => {
+      info!("Dragged window moved to new workspace: {target_workspace}");
+
+      moved_window.set_insertion_target(None);
+
+      move_container_within_tree(
+        &moved_window.clone().into(),
+        &target_workspace.clone().into(),
+        target_workspace.child_count(),
+        state,
+      )?;
+
+      // Keep focus on the dragged window. The target workspace is already
+      // displayed on its monitor, so no other workspaces need a redraw.
+      set_focused_descendant(&moved_window.clone().into(), None);
+      state.pending_sync.queue_focus_change();
+
+      Ok(target_workspace)
+    }
+    _ => Ok(workspace),
+  }
 }
 
 /// Gets the drop position for a window based on the mouse position.
 ///
-/// This approach divides the window rect into an "X", creating four
-/// triangular quadrants, to determine which side the cursor is closest to.
-fn drop_position(mouse_pos: &Point, rect: &Rect) -> DropPosition {
-  let delta_x = mouse_pos.x - rect.center_point().x;
-  let delta_y = mouse_pos.y - rect.center_point().y;
+/// The center zone spans `center_fraction` of the rect's width and height
+/// around its center point. Outside of it, this approach divides the
+/// window rect into an "X", creating four triangular quadrants, to
+/// determine which side the cursor is closest to. A `center_fraction` of
+/// 0 leaves only the four quadrants.
+#[allow(clippy::cast_precision_loss)]
+pub fn drop_position(
+  mouse_pos: &Point,
+  rect: &Rect,
+  parent_tiling_direction: &TilingDirection,
+  center_fraction: f32,
+) -> DropPosition {
+  // Deltas and extents are computed with 64-bit arithmetic to avoid
+  // overflow for points far outside of the rect, or for huge rects.
+  let center = rect.center_point();
+  let delta_x = i64::from(mouse_pos.x) - i64::from(center.x);
+  let delta_y = i64::from(mouse_pos.y) - i64::from(center.y);
+  let width = i64::from(rect.right) - i64::from(rect.left);
+  let height = i64::from(rect.bottom) - i64::from(rect.top);
+
+  let is_in_center = (delta_x.abs() as f32)
+    < width as f32 * center_fraction / 2.
+    && (delta_y.abs() as f32) < height as f32 * center_fraction / 2.;
 
-  if delta_x.abs() > delta_y.abs() {
+  if is_in_center {
+    return DropPosition::Center;
+  }
+
+  // Exactly diagonal drops favor the axis perpendicular to the parent's
+  // tiling direction, such that the drop results in a new split.
+  let is_left_or_right = match delta_x.abs().cmp(&delta_y.abs()) {
+    Ordering::Greater => true,
+    Ordering::Less => false,
+    Ordering::Equal => {
+      *parent_tiling_direction == TilingDirection::Vertical
+    }
+  };
+
+  if is_left_or_right {
     // Window is in the left or right triangle.
     if delta_x > 0 {
       DropPosition::Right
@@ -244,3 +1109,494 @@ fn drop_position(mouse_pos: &Point, rect: &Rect) -> DropPosition {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{
+    FloatingStateConfig, FullscreenStateConfig, ParsedConfig,
+  };
+
+  use super::*;
+  use crate::{
+    commands::monitor::remove_monitor,
+    events::handle_window_moved_or_resized_start,
+    models::TilingWindow,
+    test_utils::{
+      attach, monitor, non_tiling_window, split, tiling_window, wm_state,
+      wm_state_with_events, workspace,
+    },
+  };
+
+  /// Starts moving a tiling window, which makes it floating the same as
+  /// on a location change mid-drag.
+  fn start_tiling_move(
+    window: &TilingWindow,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) -> NonTilingWindow {
+    handle_window_moved_or_resized_start(&window.native(), state);
+
+    let active_drag = window.active_drag().expect("No active drag.");
+    window.set_active_drag(Some(ActiveDrag {
+      operation: Some(ActiveDragOperation::Moving),
+      ..active_drag
+    }));
+
+    update_window_state(
+      window.clone().into(),
+      WindowState::Floating(FloatingStateConfig::default()),
+      state,
+      config,
+    )
+    .unwrap()
+    .as_non_tiling_window()
+    .cloned()
+    .expect("Window isn't floating.")
+  }
+
+  /// Creates a workspace of two tiling windows with a fullscreen window
+  /// on top, and starts moving the second tiling window.
+  fn move_beside_fullscreen(
+    config: &UserConfig,
+  ) -> (Workspace, WmState, NonTilingWindow, NonTilingWindow) {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let moved_window = tiling_window(3);
+    let fullscreen_window = non_tiling_window(
+      2,
+      WindowState::Fullscreen(FullscreenStateConfig::default()),
+    );
+
+    attach(tiling_window(1), workspace.clone());
+    attach(moved_window.clone(), workspace.clone());
+    attach(fullscreen_window.clone(), workspace.clone());
+
+    let mut state = wm_state(&workspace);
+    let moved_window =
+      start_tiling_move(&moved_window, &mut state, config);
+    set_focused_descendant(&fullscreen_window.clone().into(), None);
+
+    (workspace, state, moved_window, fullscreen_window)
+  }
+
+  #[test]
+  fn splits_exact_diagonals_perpendicular_to_parent() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+
+    // Corners relative to the rect's center point at 50,50.
+    let cases = [
+      ((90, 90), DropPosition::Bottom, DropPosition::Right),
+      ((10, 10), DropPosition::Top, DropPosition::Left),
+      ((90, 10), DropPosition::Top, DropPosition::Right),
+      ((10, 90), DropPosition::Bottom, DropPosition::Left),
+    ];
+
+    for ((x, y), horizontal_expected, vertical_expected) in cases {
+      let point = Point { x, y };
+
+      assert_eq!(
+        drop_position(&point, &rect, &TilingDirection::Horizontal, 0.),
+        horizontal_expected
+      );
+      assert_eq!(
+        drop_position(&point, &rect, &TilingDirection::Vertical, 0.),
+        vertical_expected
+      );
+    }
+  }
+
+  #[test]
+  fn handles_drop_positions_near_integer_bounds() {
+    let rect = Rect::from_ltrb(i32::MIN, i32::MIN, i32::MAX, i32::MAX);
+    let horizontal = TilingDirection::Horizontal;
+
+    let cases = [
+      ((i32::MAX, 0), 0., DropPosition::Right),
+      ((i32::MIN, 0), 0., DropPosition::Left),
+      ((0, i32::MAX), 0., DropPosition::Bottom),
+      ((0, i32::MIN), 0., DropPosition::Top),
+      ((0, 0), 0.25, DropPosition::Center),
+      ((i32::MAX, i32::MAX), 0.25, DropPosition::Bottom),
+    ];
+
+    for ((x, y), center_fraction, expected) in cases {
+      assert_eq!(
+        drop_position(
+          &Point { x, y },
+          &rect,
+          &horizontal,
+          center_fraction
+        ),
+        expected
+      );
+    }
+
+    // A rect at the edge of the coordinate space.
+    let rect = Rect::from_ltrb(i32::MAX - 100, 0, i32::MAX, 100);
+
+    assert_eq!(
+      drop_position(
+        &Point { x: i32::MAX, y: 50 },
+        &rect,
+        &horizontal,
+        0.25
+      ),
+      DropPosition::Right
+    );
+    assert_eq!(
+      drop_position(&Point { x: i32::MIN, y: 50 }, &rect, &horizontal, 0.),
+      DropPosition::Left
+    );
+  }
+
+  #[test]
+  fn picks_lowest_index_between_equidistant_siblings() {
+    // The point is centered in the gap between the two rects.
+    let indexed_rects = [
+      (1, Rect::from_xy(200, 0, 100, 100)),
+      (0, Rect::from_xy(0, 0, 100, 100)),
+    ];
+
+    assert_eq!(
+      nearest_rect_index(&indexed_rects, &Point { x: 150, y: 50 }),
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn prefers_rect_containing_point() {
+    // Overlapping rects, where the second one contains the point.
+    let indexed_rects = [
+      (0, Rect::from_xy(0, 0, 100, 100)),
+      (1, Rect::from_xy(90, 0, 100, 100)),
+    ];
+
+    assert_eq!(
+      nearest_rect_index(&indexed_rects, &Point { x: 150, y: 50 }),
+      Some(1)
+    );
+  }
+
+  #[test]
+  fn classifies_drag_result() {
+    let cases = [
+      (DragResult::Tiled, false, DragResult::Tiled),
+      (DragResult::Tiled, true, DragResult::MovedMonitor),
+      (DragResult::Floated, false, DragResult::Floated),
+      (DragResult::Floated, true, DragResult::MovedMonitor),
+      (DragResult::SnappedBack, false, DragResult::SnappedBack),
+      (DragResult::SnappedBack, true, DragResult::SnappedBack),
+    ];
+
+    for (drag_result, is_monitor_change, expected) in cases {
+      assert_eq!(
+        drag_ended_result(drag_result, is_monitor_change),
+        expected
+      );
+    }
+  }
+
+  #[test]
+  fn relocates_window_from_removed_monitor() {
+    let primary_workspace = workspace(TilingDirection::Horizontal);
+    attach(tiling_window(1), primary_workspace.clone());
+
+    let mut state = wm_state(&primary_workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    let removed_monitor =
+      monitor(1, Rect::from_xy(1920, 0, 1920, 1080), false);
+    let removed_workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(2);
+
+    attach(removed_monitor.clone(), state.root_container.clone());
+    attach(removed_workspace.clone(), removed_monitor.clone());
+    attach(window.clone(), removed_workspace.clone());
+
+    handle_window_moved_or_resized_start(&window.native(), &mut state);
+
+    let start_monitor_id = window
+      .active_drag()
+      .and_then(|active_drag| active_drag.start_monitor_id);
+
+    assert_eq!(start_monitor_id, Some(removed_monitor.id()));
+
+    // The window's workspace gets moved to the primary monitor without
+    // being displayed.
+    remove_monitor(removed_monitor, &mut state, &config).unwrap();
+    assert!(!removed_workspace.is_displayed());
+
+    relocate_from_removed_monitor(
+      &window.clone().into(),
+      start_monitor_id,
+      &mut state,
+    )
+    .unwrap();
+
+    assert_eq!(
+      window.workspace().map(|workspace| workspace.id()),
+      Some(primary_workspace.id())
+    );
+  }
+
+  #[test]
+  fn keeps_window_on_hidden_workspace_of_existing_monitor() {
+    let displayed_workspace = workspace(TilingDirection::Horizontal);
+    let hidden_workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(1);
+
+    attach(tiling_window(2), displayed_workspace.clone());
+    attach(window.clone(), hidden_workspace.clone());
+
+    let mut state = wm_state(&displayed_workspace);
+    let monitor = displayed_workspace.monitor().expect("No monitor.");
+    attach(hidden_workspace.clone(), monitor.clone());
+
+    // E.g. the workspace was switched mid-drag.
+    relocate_from_removed_monitor(
+      &window.clone().into(),
+      Some(monitor.id()),
+      &mut state,
+    )
+    .unwrap();
+
+    assert_eq!(
+      window.workspace().map(|workspace| workspace.id()),
+      Some(hidden_workspace.id())
+    );
+  }
+
+  #[test]
+  fn handles_one_start_and_end_per_drag() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(1);
+    attach(window.clone(), workspace.clone());
+    attach(tiling_window(2), workspace.clone());
+
+    let (mut state, mut event_rx) = wm_state_with_events(&workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+    let native_window = window.native().clone();
+
+    // Some systems send the start and end events more than once for a
+    // single drag.
+    handle_window_moved_or_resized_start(&native_window, &mut state);
+    handle_window_moved_or_resized_start(&native_window, &mut state);
+
+    // Widen the window by dragging its right edge.
+    let old_rect = window.to_rect().unwrap();
+    let new_rect = Rect::from_xy(
+      old_rect.x(),
+      old_rect.y(),
+      old_rect.width() + 100,
+      old_rect.height(),
+    );
+
+    let window_container: WindowContainer = window.clone().into();
+
+    let layout = |workspace: &Workspace| {
+      workspace
+        .tiling_children()
+        .map(|child| (child.id(), child.index(), child.tiling_size()))
+        .collect::<Vec<_>>()
+    };
+
+    end_move_or_resize(
+      &window_container,
+      new_rect.clone(),
+      None,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    let layout_after_first_end = layout(&workspace);
+
+    end_move_or_resize(
+      &window_container,
+      new_rect,
+      None,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    // The repeated end shouldn't resize the window again.
+    assert_eq!(layout(&workspace), layout_after_first_end);
+
+    let events =
+      std::iter::from_fn(|| event_rx.try_recv().ok()).collect::<Vec<_>>();
+
+    let drag_started_count = events
+      .iter()
+      .filter(|event| matches!(event, WmEvent::DragStarted { .. }))
+      .count();
+
+    let moved_or_resized_count = events
+      .iter()
+      .filter(|event| {
+        matches!(event, WmEvent::WindowMovedOrResizedEnded { .. })
+      })
+      .count();
+
+    let drag_results = events
+      .iter()
+      .filter_map(|event| match event {
+        WmEvent::DragEnded { result, .. } => Some(*result),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(drag_started_count, 1);
+    assert_eq!(moved_or_resized_count, 1);
+    assert_eq!(drag_results, [DragResult::Tiled]);
+    assert!(window.tiling_size() > 0.5);
+  }
+
+  #[test]
+  fn ignores_drop_onto_fullscreen_window() {
+    let mut config = UserConfig::from_value(ParsedConfig::default());
+    config.value.window_behavior.drop.on_fullscreen =
+      DropOnFullscreen::Ignore;
+
+    let (workspace, mut state, moved_window, fullscreen_window) =
+      move_beside_fullscreen(&config);
+
+    let (drag_result, window) = drop_as_tiling_window(
+      &moved_window,
+      &Point { x: 100, y: 500 },
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(drag_result, DragResult::SnappedBack);
+    assert!(window.is_tiling_window());
+    assert_eq!(
+      window.parent().map(|parent| parent.id()),
+      Some(workspace.id())
+    );
+    assert_eq!(window.index(), 1);
+    assert!(matches!(
+      fullscreen_window.state(),
+      WindowState::Fullscreen(_)
+    ));
+  }
+
+  #[test]
+  fn exits_fullscreen_on_drop_onto_fullscreen_window() {
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    let (workspace, mut state, moved_window, fullscreen_window) =
+      move_beside_fullscreen(&config);
+
+    // Dropped onto the left edge of the first window.
+    let (drag_result, window) = drop_as_tiling_window(
+      &moved_window,
+      &Point { x: 100, y: 500 },
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(drag_result, DragResult::Tiled);
+    assert!(window.is_tiling_window());
+    assert_eq!(
+      window.parent().map(|parent| parent.id()),
+      Some(workspace.id())
+    );
+    assert_eq!(window.index(), 0);
+
+    let prev_fullscreen_window = state
+      .window_from_native(&fullscreen_window.native())
+      .expect("No previously fullscreen window.");
+
+    assert_eq!(prev_fullscreen_window.state(), WindowState::Tiling);
+  }
+
+  #[test]
+  fn snaps_back_only_tiling_window_in_split() {
+    // Layout: H[V[1]] with two floating windows.
+    let workspace = workspace(TilingDirection::Horizontal);
+    let split = split(TilingDirection::Vertical);
+    let window = tiling_window(1);
+
+    attach(split.clone(), workspace.clone());
+    attach(window.clone(), split.clone());
+
+    for handle in [2, 3] {
+      attach(
+        non_tiling_window(
+          handle,
+          WindowState::Floating(FloatingStateConfig::default()),
+        ),
+        workspace.clone(),
+      );
+    }
+
+    let (mut state, mut event_rx) = wm_state_with_events(&workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    handle_window_moved_or_resized_start(&window.native(), &mut state);
+
+    // Shrink the window by dragging its right edge.
+    let old_rect = window.to_rect().unwrap();
+    let new_rect = Rect::from_xy(
+      old_rect.x(),
+      old_rect.y(),
+      old_rect.width() - 100,
+      old_rect.height(),
+    );
+
+    end_move_or_resize(
+      &window.clone().into(),
+      new_rect,
+      None,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    let drag_results = std::iter::from_fn(|| event_rx.try_recv().ok())
+      .filter_map(|event| match event {
+        WmEvent::DragEnded { result, .. } => Some(result),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(drag_results, [DragResult::SnappedBack]);
+    assert!((window.tiling_size() - 1.).abs() < f32::EPSILON);
+    assert!((split.tiling_size() - 1.).abs() < f32::EPSILON);
+    assert_eq!(window.to_rect().unwrap(), old_rect);
+  }
+
+  #[test]
+  fn snaps_back_on_drop_onto_origin() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(2);
+
+    attach(tiling_window(1), workspace.clone());
+    attach(window.clone(), workspace.clone());
+    attach(tiling_window(3), workspace.clone());
+
+    let mut state = wm_state(&workspace);
+    let config = UserConfig::from_value(ParsedConfig::default());
+
+    let origin_rect = window.to_rect().unwrap();
+    let moved_window = start_tiling_move(&window, &mut state, &config);
+
+    let (drag_result, window) = drop_as_tiling_window(
+      &moved_window,
+      &origin_rect.center_point(),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(drag_result, DragResult::SnappedBack);
+    assert!(window.is_tiling_window());
+    assert_eq!(
+      window.parent().map(|parent| parent.id()),
+      Some(workspace.id())
+    );
+    assert_eq!(window.index(), 1);
+  }
+}
@@ -1,7 +1,14 @@
-use wm_common::ActiveDrag;
-use wm_platform::NativeWindow;
+use wm_common::{
+  ActiveDrag, ActiveDragOperation, DragTilingOrigin, WmEvent,
+};
+use wm_platform::{NativeWindow, Platform};
 
-use crate::{traits::WindowGetters, wm_state::WmState};
+use crate::{
+  commands::window::unpeek_window,
+  models::WindowSlot,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  wm_state::WmState,
+};
 
 /// Handles the event for when a window is started being moved or resized
 /// by the user (e.g. via the window's drag handles).
@@ -17,9 +24,77 @@ pub fn handle_window_moved_or_resized_start(
   let found_window = state.window_from_native(native_window);
 
   if let Some(found_window) = found_window {
-    found_window.set_active_drag(Some(ActiveDrag {
-      operation: None,
+    // Ignore repeated start events for a drag that's already in progress.
+    // Its state was recorded when the drag was first started.
+    if found_window.active_drag().is_some() {
+      return;
+    }
+
+    // Revert the window's peek before it gets dragged.
+    if state
+      .peeked_window
+      .as_ref()
+      .is_some_and(|peeked| peeked.id() == found_window.id())
+    {
+      unpeek_window(state);
+    }
+
+    // A new drag has started, so its end event shouldn't be debounced.
+    state.drag_end_timestamps.remove(&found_window.id());
+
+    let start_position = Platform::mouse_position().ok();
+
+    // Whether the drag is a move or resize is determined by what's under
+    // the cursor when the drag is started. If this can't be determined
+    // (e.g. the window's frame can't be retrieved), it's instead inferred
+    // from the window's location changes.
+    let operation = start_position
+      .as_ref()
+      .and_then(|point| {
+        found_window.native().is_resize_handle_at(point).ok()
+      })
+      .map(|is_resize| {
+        if is_resize {
+          ActiveDragOperation::Resizing
+        } else {
+          ActiveDragOperation::Moving
+        }
+      });
+
+    // Record the window's slot so that the drag can be undone.
+    if let Some(slot) = found_window
+      .as_tiling_window()
+      .and_then(WindowSlot::from_window)
+    {
+      state.layout_history.begin(slot);
+    }
+
+    let tiling_origin = match found_window.as_tiling_container() {
+      Ok(tiling_window) => tiling_window
+        .parent()
+        .zip(tiling_window.to_rect().ok())
+        .map(|(parent, rect)| DragTilingOrigin {
+          parent_id: parent.id(),
+          index: tiling_window.index(),
+          rect,
+        }),
+      Err(_) => None,
+    };
+
+    let active_drag = ActiveDrag {
+      operation,
       is_from_tiling: found_window.is_tiling_window(),
-    }));
+      start_position,
+      tiling_origin,
+      start_monitor_id: found_window.monitor().map(|monitor| monitor.id()),
+      edge_dwell: None,
+    };
+
+    state.emit_event(WmEvent::DragStarted {
+      window_id: found_window.id(),
+      operation: active_drag.operation,
+    });
+
+    found_window.set_active_drag(Some(active_drag));
   }
 }
@@ -1,11 +1,13 @@
 mod handle_display_settings_changed;
 mod handle_mouse_move;
+mod handle_mouse_wheel;
 mod handle_window_destroyed;
 mod handle_window_focused;
 mod handle_window_hidden;
 mod handle_window_location_changed;
 mod handle_window_minimize_ended;
 mod handle_window_minimized;
+mod handle_window_moved_or_resized_canceled;
 mod handle_window_moved_or_resized_end;
 mod handle_window_moved_or_resized_start;
 mod handle_window_shown;
@@ -13,12 +15,14 @@ mod handle_window_title_changed;
 
 pub use handle_display_settings_changed::*;
 pub use handle_mouse_move::*;
+pub use handle_mouse_wheel::*;
 pub use handle_window_destroyed::*;
 pub use handle_window_focused::*;
 pub use handle_window_hidden::*;
 pub use handle_window_location_changed::*;
 pub use handle_window_minimize_ended::*;
 pub use handle_window_minimized::*;
+pub use handle_window_moved_or_resized_canceled::*;
 pub use handle_window_moved_or_resized_end::*;
 pub use handle_window_moved_or_resized_start::*;
 pub use handle_window_shown::*;
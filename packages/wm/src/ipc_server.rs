@@ -14,9 +14,10 @@ use uuid::Uuid;
 use wm_common::{
   AppCommand, AppMetadataData, BindingModesData, ClientResponseData,
   ClientResponseMessage, CommandData, EventSubscribeData,
-  EventSubscriptionMessage, FocusedData, MonitorsData, QueryCommand,
-  ServerMessage, SubscribableEvent, TilingDirectionData, WindowsData,
-  WmEvent, WorkspacesData, DEFAULT_IPC_PORT,
+  EventSubscriptionMessage, FocusedData, MonitorsData, Point,
+  QueryCommand, ServerMessage, SubscribableEvent, TilingDirectionData,
+  WindowAtPointData, WindowsData, WmEvent, WorkspacesData,
+  DEFAULT_IPC_PORT,
 };
 
 use crate::{
@@ -242,6 +243,15 @@ impl IpcServer {
         QueryCommand::Paused => {
           ClientResponseData::Paused(wm.state.is_paused)
         }
+        QueryCommand::WindowAtPoint { x, y } => {
+          ClientResponseData::WindowAtPoint(WindowAtPointData {
+            window: wm
+              .state
+              .window_at_point(&Point { x, y })
+              .map(|window| window.to_dto())
+              .transpose()?,
+          })
+        }
       },
       AppCommand::Command {
         subject_container_id,
@@ -356,6 +366,8 @@ impl IpcServer {
       WmEvent::BindingModesChanged { .. } => {
         SubscribableEvent::BindingModesChanged
       }
+      WmEvent::DragEnded { .. } => SubscribableEvent::DragEnded,
+      WmEvent::DragStarted { .. } => SubscribableEvent::DragStarted,
       WmEvent::FocusChanged { .. } => SubscribableEvent::FocusChanged,
       WmEvent::FocusedContainerMoved { .. } => {
         SubscribableEvent::FocusedContainerMoved
@@ -370,6 +382,9 @@ impl IpcServer {
         SubscribableEvent::UserConfigChanged
       }
       WmEvent::WindowManaged { .. } => SubscribableEvent::WindowManaged,
+      WmEvent::WindowMovedOrResizedEnded { .. } => {
+        SubscribableEvent::WindowMovedOrResizedEnded
+      }
       WmEvent::WindowUnmanaged { .. } => {
         SubscribableEvent::WindowUnmanaged
       }
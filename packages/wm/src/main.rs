@@ -32,6 +32,8 @@ mod ipc_server;
 mod models;
 mod pending_sync;
 mod sys_tray;
+#[cfg(test)]
+mod test_utils;
 mod traits;
 mod user_config;
 mod wm;
@@ -113,6 +115,9 @@ async fn start_wm(
         debug!("Received platform event: {:?}", event);
         wm.process_event(event, &mut config)
       },
+      Some(()) = wm.edge_dwell_rx.recv() => {
+        wm.process_edge_dwell(&mut config)
+      },
       Some((
         message,
         response_tx,
@@ -0,0 +1,19 @@
+use wm_common::Point;
+
+use super::WindowContainer;
+
+/// A tiling window that is grabbed via the `grab` command, so that it can
+/// be placed with the keyboard.
+#[derive(Clone)]
+pub struct KeyboardGrab {
+  /// Tiling window that is being moved.
+  pub window: WindowContainer,
+
+  /// Point that the window gets dropped at. Used in place of the cursor
+  /// position of a mouse drop.
+  pub anchor: Point,
+
+  /// Cursor position from before the grab. Restored if the grab is
+  /// canceled.
+  pub cursor_position: Option<Point>,
+}
@@ -0,0 +1,206 @@
+use std::collections::{HashMap, VecDeque};
+
+use uuid::Uuid;
+use wm_common::WindowState;
+
+use super::TilingWindow;
+use crate::traits::{CommonGetters, TilingSizeGetters, WindowGetters};
+
+/// Position of a tiling window in the tree. Recorded before a layout
+/// change so that the change can be undone.
+#[derive(Clone, Debug)]
+pub struct WindowSlot {
+  pub window_id: Uuid,
+
+  /// ID of the window's parent container.
+  pub parent_id: Uuid,
+
+  /// Index of the window within its parent.
+  pub index: usize,
+
+  pub state: WindowState,
+
+  /// Tiling sizes of the window and its tiling siblings.
+  pub tiling_sizes: Vec<(Uuid, f32)>,
+}
+
+impl WindowSlot {
+  /// Records the current slot of the window. Returns `None` if the window
+  /// is detached.
+  pub fn from_window(window: &TilingWindow) -> Option<Self> {
+    let parent = window.parent()?;
+
+    let tiling_sizes = parent
+      .tiling_children()
+      .map(|child| (child.id(), child.tiling_size()))
+      .collect();
+
+    Some(Self {
+      window_id: window.id(),
+      parent_id: parent.id(),
+      index: window.index(),
+      state: window.state(),
+      tiling_sizes,
+    })
+  }
+}
+
+/// Bounded undo and redo stacks of layout changes.
+#[derive(Default)]
+pub struct LayoutHistory {
+  undo_stack: VecDeque<WindowSlot>,
+  redo_stack: Vec<WindowSlot>,
+
+  /// Slots of windows that are currently being dragged. These are added
+  /// to the undo stack if the drag ends up changing the layout.
+  pending: HashMap<Uuid, WindowSlot>,
+}
+
+impl LayoutHistory {
+  /// Records a layout change that can be undone. Clears the redo stack,
+  /// since the changes there no longer follow from the current layout.
+  pub fn push(&mut self, slot: WindowSlot, limit: usize) {
+    self.push_undo(slot, limit);
+    self.redo_stack.clear();
+  }
+
+  /// Records the slot of a window that's about to be dragged.
+  pub fn begin(&mut self, slot: WindowSlot) {
+    self.pending.insert(slot.window_id, slot);
+  }
+
+  /// Records the pending slot of a dragged window as a layout change.
+  pub fn commit(&mut self, window_id: Uuid, limit: usize) {
+    if let Some(slot) = self.pending.remove(&window_id) {
+      self.push(slot, limit);
+    }
+  }
+
+  /// Drops the pending slot of a dragged window.
+  pub fn discard(&mut self, window_id: Uuid) {
+    self.pending.remove(&window_id);
+  }
+
+  pub fn pop_undo(&mut self) -> Option<WindowSlot> {
+    self.undo_stack.pop_back()
+  }
+
+  pub fn pop_redo(&mut self) -> Option<WindowSlot> {
+    self.redo_stack.pop()
+  }
+
+  /// Adds to the undo stack without clearing the redo stack. Oldest
+  /// entries are dropped past the limit.
+  pub fn push_undo(&mut self, slot: WindowSlot, limit: usize) {
+    if limit == 0 {
+      return;
+    }
+
+    self.undo_stack.push_back(slot);
+
+    while self.undo_stack.len() > limit {
+      self.undo_stack.pop_front();
+    }
+  }
+
+  pub fn push_redo(&mut self, slot: WindowSlot) {
+    self.redo_stack.push(slot);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use uuid::Uuid;
+  use wm_common::WindowState;
+
+  use super::*;
+
+  fn slot(window_id: Uuid) -> WindowSlot {
+    WindowSlot {
+      window_id,
+      parent_id: Uuid::new_v4(),
+      index: 0,
+      state: WindowState::Tiling,
+      tiling_sizes: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn push_clears_redo_stack() {
+    let mut history = LayoutHistory::default();
+    history.push_redo(slot(Uuid::new_v4()));
+
+    history.push(slot(Uuid::new_v4()), 10);
+
+    assert!(history.pop_redo().is_none());
+    assert!(history.pop_undo().is_some());
+  }
+
+  #[test]
+  fn push_undo_keeps_redo_stack() {
+    let mut history = LayoutHistory::default();
+    history.push_redo(slot(Uuid::new_v4()));
+
+    history.push_undo(slot(Uuid::new_v4()), 10);
+
+    assert!(history.pop_redo().is_some());
+  }
+
+  #[test]
+  fn commit_moves_pending_slot_to_undo_stack() {
+    let mut history = LayoutHistory::default();
+    let window_id = Uuid::new_v4();
+    history.begin(slot(window_id));
+
+    history.commit(window_id, 10);
+
+    assert_eq!(
+      history.pop_undo().map(|slot| slot.window_id),
+      Some(window_id)
+    );
+
+    // The pending slot is consumed by the commit.
+    history.commit(window_id, 10);
+    assert!(history.pop_undo().is_none());
+  }
+
+  #[test]
+  fn discard_drops_pending_slot() {
+    let mut history = LayoutHistory::default();
+    let window_id = Uuid::new_v4();
+    history.begin(slot(window_id));
+
+    history.discard(window_id);
+    history.commit(window_id, 10);
+
+    assert!(history.pop_undo().is_none());
+  }
+
+  #[test]
+  fn drops_oldest_entries_past_limit() {
+    let mut history = LayoutHistory::default();
+    let window_ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+
+    for window_id in window_ids {
+      history.push(slot(window_id), 2);
+    }
+
+    assert_eq!(
+      history.pop_undo().map(|slot| slot.window_id),
+      Some(window_ids[2])
+    );
+    assert_eq!(
+      history.pop_undo().map(|slot| slot.window_id),
+      Some(window_ids[1])
+    );
+    assert!(history.pop_undo().is_none());
+  }
+
+  #[test]
+  fn zero_limit_disables_history() {
+    let mut history = LayoutHistory::default();
+    history.push(slot(Uuid::new_v4()), 0);
+
+    assert!(history.pop_undo().is_none());
+  }
+}
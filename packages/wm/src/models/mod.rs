@@ -1,5 +1,7 @@
 mod container;
 mod insertion_target;
+mod keyboard_grab;
+mod layout_history;
 mod monitor;
 mod non_tiling_window;
 mod root_container;
@@ -10,6 +12,8 @@ mod workspace_target;
 
 pub use container::*;
 pub use insertion_target::*;
+pub use keyboard_grab::*;
+pub use layout_history::*;
 pub use monitor::*;
 pub use non_tiling_window::*;
 pub use root_container::*;
@@ -40,6 +40,8 @@ struct NonTilingWindowInner {
   has_custom_floating_placement: bool,
   done_window_rules: Vec<WindowRuleConfig>,
   active_drag: Option<ActiveDrag>,
+  aspect_ratio_lock: Option<f32>,
+  is_sticky: bool,
 }
 
 impl NonTilingWindow {
@@ -55,6 +57,7 @@ impl NonTilingWindow {
     has_custom_floating_placement: bool,
     done_window_rules: Vec<WindowRuleConfig>,
     active_drag: Option<ActiveDrag>,
+    aspect_ratio_lock: Option<f32>,
   ) -> Self {
     let window = NonTilingWindowInner {
       id: id.unwrap_or_else(Uuid::new_v4),
@@ -72,6 +75,8 @@ impl NonTilingWindow {
       has_custom_floating_placement,
       done_window_rules,
       active_drag,
+      aspect_ratio_lock,
+      is_sticky: false,
     };
 
     Self(Rc::new(RefCell::new(window)))
@@ -88,6 +93,16 @@ impl NonTilingWindow {
     self.0.borrow_mut().insertion_target = insertion_target;
   }
 
+  /// Whether the window follows across workspace switches on its
+  /// monitor. Only applies while the window is floating.
+  pub fn is_sticky(&self) -> bool {
+    self.0.borrow().is_sticky
+  }
+
+  pub fn set_sticky(&self, is_sticky: bool) {
+    self.0.borrow_mut().is_sticky = is_sticky;
+  }
+
   pub fn to_tiling(&self, gaps_config: GapsConfig) -> TilingWindow {
     TilingWindow::new(
       Some(self.id()),
@@ -99,6 +114,7 @@ impl NonTilingWindow {
       gaps_config,
       self.done_window_rules(),
       self.active_drag(),
+      self.aspect_ratio_lock(),
     )
   }
 
@@ -46,6 +46,7 @@ struct TilingWindowInner {
   gaps_config: GapsConfig,
   done_window_rules: Vec<WindowRuleConfig>,
   active_drag: Option<ActiveDrag>,
+  aspect_ratio_lock: Option<f32>,
 }
 
 impl TilingWindow {
@@ -60,6 +61,7 @@ impl TilingWindow {
     gaps_config: GapsConfig,
     done_window_rules: Vec<WindowRuleConfig>,
     active_drag: Option<ActiveDrag>,
+    aspect_ratio_lock: Option<f32>,
   ) -> Self {
     let window = TilingWindowInner {
       id: id.unwrap_or_else(Uuid::new_v4),
@@ -78,6 +80,7 @@ impl TilingWindow {
       gaps_config,
       done_window_rules,
       active_drag,
+      aspect_ratio_lock,
     };
 
     Self(Rc::new(RefCell::new(window)))
@@ -99,6 +102,7 @@ impl TilingWindow {
       self.has_custom_floating_placement(),
       self.done_window_rules(),
       self.active_drag(),
+      self.aspect_ratio_lock(),
     )
   }
 
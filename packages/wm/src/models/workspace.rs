@@ -7,17 +7,20 @@ use std::{
 use anyhow::Context;
 use uuid::Uuid;
 use wm_common::{
-  ContainerDto, GapsConfig, Rect, TilingDirection, WorkspaceConfig,
-  WorkspaceDto,
+  ContainerDto, GapsConfig, Rect, TilingDirection, WindowState,
+  WorkspaceConfig, WorkspaceDto,
 };
 
 use crate::{
   impl_common_getters, impl_container_debug,
   impl_tiling_direction_getters,
   models::{
-    Container, DirectionContainer, TilingContainer, WindowContainer,
+    Container, DirectionContainer, NonTilingWindow, TilingContainer,
+    WindowContainer,
+  },
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters, WindowGetters,
   },
-  traits::{CommonGetters, PositionGetters, TilingDirectionGetters},
 };
 
 #[derive(Clone)]
@@ -71,6 +74,24 @@ impl Workspace {
       .is_some_and(|workspace| workspace.id() == self.id())
   }
 
+  /// Gets the most recently focused descendant if it's a fullscreen
+  /// window.
+  pub fn focused_fullscreen_window(&self) -> Option<NonTilingWindow> {
+    match self.descendant_focus_order().next()? {
+      Container::NonTilingWindow(window)
+        if matches!(window.state(), WindowState::Fullscreen(_)) =>
+      {
+        Some(window)
+      }
+      _ => None,
+    }
+  }
+
+  /// Gaps config that applies to containers within the workspace.
+  pub fn gaps_config(&self) -> GapsConfig {
+    self.0.borrow().gaps_config.clone()
+  }
+
   pub fn set_gaps_config(&self, gaps_config: GapsConfig) {
     self.0.borrow_mut().gaps_config = gaps_config;
   }
@@ -156,3 +177,40 @@ impl std::fmt::Display for Workspace {
     )
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FullscreenStateConfig, TilingDirection, WindowState};
+
+  use crate::{
+    commands::container::set_focused_descendant,
+    test_utils::{attach, non_tiling_window, tiling_window, workspace},
+    traits::CommonGetters,
+  };
+
+  #[test]
+  fn focused_fullscreen_window_follows_focus_order() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let tiling_window = tiling_window(1);
+    let fullscreen_window = non_tiling_window(
+      2,
+      WindowState::Fullscreen(FullscreenStateConfig::default()),
+    );
+
+    attach(tiling_window.clone(), workspace.clone());
+    attach(fullscreen_window.clone(), workspace.clone());
+
+    set_focused_descendant(&fullscreen_window.clone().into(), None);
+    assert_eq!(
+      workspace
+        .focused_fullscreen_window()
+        .map(|window| window.id()),
+      Some(fullscreen_window.id())
+    );
+
+    // The fullscreen window no longer counts once it isn't the most
+    // recently focused.
+    set_focused_descendant(&tiling_window.into(), None);
+    assert!(workspace.focused_fullscreen_window().is_none());
+  }
+}
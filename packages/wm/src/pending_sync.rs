@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use uuid::Uuid;
+use wm_common::CursorJumpConfig;
 
 use crate::{
   models::{Container, Workspace},
@@ -30,6 +31,10 @@ pub struct PendingSync {
   /// Whether to jump the cursor to the focused container (if enabled in
   /// user config).
   needs_cursor_jump: bool,
+
+  /// Cursor jump settings to use instead of the ones in user config for
+  /// the pending cursor jump.
+  cursor_jump_override: Option<CursorJumpConfig>,
 }
 
 impl PendingSync {
@@ -49,6 +54,7 @@ impl PendingSync {
     self.needs_focused_effect_update = false;
     self.needs_all_effects_update = false;
     self.needs_cursor_jump = false;
+    self.cursor_jump_override = None;
     self
   }
 
@@ -116,6 +122,17 @@ impl PendingSync {
     self
   }
 
+  /// Queues a cursor jump that uses the given settings instead of the
+  /// ones in user config.
+  pub fn queue_cursor_jump_with(
+    &mut self,
+    cursor_jump: CursorJumpConfig,
+  ) -> &mut Self {
+    self.needs_cursor_jump = true;
+    self.cursor_jump_override = Some(cursor_jump);
+    self
+  }
+
   pub fn needs_focus_update(&self) -> bool {
     self.needs_focus_update
   }
@@ -132,11 +149,81 @@ impl PendingSync {
     self.needs_cursor_jump
   }
 
+  pub fn cursor_jump_override(&self) -> Option<&CursorJumpConfig> {
+    self.cursor_jump_override.as_ref()
+  }
+
   pub fn containers_to_redraw(&self) -> &HashMap<Uuid, Container> {
     &self.containers_to_redraw
   }
 
+  /// Gets the queued containers, excluding any whose ancestor is also
+  /// queued (since redrawing the ancestor already covers them).
+  ///
+  /// This is resolved at read time rather than on insertion, since the
+  /// tree can be restructured between queueing and the next sync (e.g.
+  /// repeatedly during a drag).
+  pub fn coalesced_containers_to_redraw(
+    &self,
+  ) -> impl Iterator<Item = &Container> {
+    self.containers_to_redraw.values().filter(|container| {
+      !container.ancestors().any(|ancestor| {
+        self.containers_to_redraw.contains_key(&ancestor.id())
+      })
+    })
+  }
+
+  /// Number of containers that will be redrawn on the next sync, after
+  /// coalescing.
+  pub fn queued_len(&self) -> usize {
+    self.coalesced_containers_to_redraw().count()
+  }
+
   pub fn workspaces_to_reorder(&self) -> &Vec<Workspace> {
     &self.workspaces_to_reorder
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::TilingDirection;
+
+  use super::*;
+  use crate::test_utils::{attach, tiling_window, workspace};
+
+  #[test]
+  fn coalesces_container_queued_twice() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(1);
+    attach(window.clone(), workspace.clone());
+
+    let mut pending_sync = PendingSync::default();
+    pending_sync
+      .queue_container_to_redraw(window.clone())
+      .queue_container_to_redraw(window.clone());
+
+    let queued_ids = pending_sync
+      .coalesced_containers_to_redraw()
+      .map(CommonGetters::id)
+      .collect::<Vec<_>>();
+
+    assert_eq!(queued_ids, [window.id()]);
+  }
+
+  #[test]
+  fn coalesces_descendant_of_queued_container() {
+    let workspace = workspace(TilingDirection::Horizontal);
+    let window = tiling_window(1);
+    attach(window.clone(), workspace.clone());
+
+    let mut pending_sync = PendingSync::default();
+    pending_sync
+      .queue_container_to_redraw(window)
+      .queue_container_to_redraw(workspace.clone());
+
+    assert_eq!(pending_sync.queued_len(), 1);
+    assert!(pending_sync
+      .coalesced_containers_to_redraw()
+      .all(|container| container.id() == workspace.id()));
+  }
+}
@@ -0,0 +1,148 @@
+//! Helpers for building container trees in unit tests.
+
+use tokio::sync::mpsc;
+use wm_common::{
+  GapsConfig, LengthValue, Rect, RectDelta, TilingDirection, WindowState,
+  WmEvent, WorkspaceConfig,
+};
+use wm_platform::{NativeMonitor, NativeWindow};
+
+use crate::{
+  commands::container::attach_container,
+  models::{
+    Container, Monitor, NonTilingWindow, SplitContainer, TilingWindow,
+    Workspace,
+  },
+  wm_state::WmState,
+};
+
+/// Creates a WM state whose root contains a single 1920x1080 primary
+/// monitor with the given workspace. Events aren't received by anything,
+/// since the state is never marked as initialized.
+pub fn wm_state(workspace: &Workspace) -> WmState {
+  let (event_tx, _) = mpsc::unbounded_channel();
+  wm_state_with_event_tx(workspace, event_tx)
+}
+
+/// Creates a WM state like `wm_state`, along with a receiver for the
+/// events it emits.
+pub fn wm_state_with_events(
+  workspace: &Workspace,
+) -> (WmState, mpsc::UnboundedReceiver<WmEvent>) {
+  let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+  let mut state = wm_state_with_event_tx(workspace, event_tx);
+  state.set_initialized();
+
+  (state, event_rx)
+}
+
+fn wm_state_with_event_tx(
+  workspace: &Workspace,
+  event_tx: mpsc::UnboundedSender<WmEvent>,
+) -> WmState {
+  let (exit_tx, _) = mpsc::unbounded_channel();
+  let (edge_dwell_tx, _) = mpsc::unbounded_channel();
+
+  let state = WmState::new(event_tx, exit_tx, edge_dwell_tx);
+  let monitor = monitor(0, Rect::from_xy(0, 0, 1920, 1080), true);
+
+  attach(monitor.clone(), state.root_container.clone());
+  attach(workspace.clone(), monitor);
+
+  state
+}
+
+/// Creates a monitor with a fake native handle and the given rect.
+pub fn monitor(handle: isize, rect: Rect, is_primary: bool) -> Monitor {
+  Monitor::new(NativeMonitor::from_rect(handle, rect, is_primary))
+}
+
+pub fn workspace(tiling_direction: TilingDirection) -> Workspace {
+  Workspace::new(
+    WorkspaceConfig {
+      name: "1".to_string(),
+      display_name: None,
+      bind_to_monitor: None,
+      keep_alive: false,
+    },
+    GapsConfig::default(),
+    tiling_direction,
+  )
+}
+
+pub fn split(tiling_direction: TilingDirection) -> SplitContainer {
+  SplitContainer::new(tiling_direction, GapsConfig::default())
+}
+
+/// Creates a native window with a fake handle. Its names are preset, so
+/// that they're never queried from the system.
+pub fn native_window(handle: isize) -> NativeWindow {
+  NativeWindow::with_names(handle, "test.exe", "TestWindow")
+}
+
+/// Creates a tiling window with a fake native handle.
+pub fn tiling_window(handle: isize) -> TilingWindow {
+  app_tiling_window(handle, "test.exe")
+}
+
+/// Creates a tiling window with a fake native handle that belongs to the
+/// given process.
+pub fn app_tiling_window(
+  handle: isize,
+  process_name: &str,
+) -> TilingWindow {
+  TilingWindow::new(
+    None,
+    NativeWindow::with_names(handle, process_name, "TestWindow"),
+    None,
+    RectDelta::new(
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+    ),
+    Rect::from_xy(0, 0, 100, 100),
+    false,
+    GapsConfig::default(),
+    Vec::new(),
+    None,
+    None,
+  )
+}
+
+/// Creates a non-tiling window in the given state with a fake native
+/// handle.
+pub fn non_tiling_window(
+  handle: isize,
+  state: WindowState,
+) -> NonTilingWindow {
+  NonTilingWindow::new(
+    None,
+    native_window(handle),
+    state,
+    None,
+    RectDelta::new(
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+    ),
+    None,
+    Rect::from_xy(0, 0, 100, 100),
+    false,
+    Vec::new(),
+    None,
+    None,
+  )
+}
+
+/// Attaches the child as the last child of the parent.
+pub fn attach<C, P>(child: C, parent: P)
+where
+  C: Into<Container>,
+  P: Into<Container>,
+{
+  attach_container(&child.into(), &parent.into(), None)
+    .expect("Failed to attach container.");
+}
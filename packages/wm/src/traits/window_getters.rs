@@ -120,13 +120,20 @@ pub trait WindowGetters {
   fn active_drag(&self) -> Option<ActiveDrag>;
 
   fn set_active_drag(&self, active_drag: Option<ActiveDrag>);
+
+  /// Aspect ratio (width divided by height) to preserve when the window
+  /// is tiling. The window is centered within its tile when set.
+  fn aspect_ratio_lock(&self) -> Option<f32>;
+
+  fn set_aspect_ratio_lock(&self, aspect_ratio_lock: Option<f32>);
 }
 
 /// Implements the `WindowGetters` trait for a given struct.
 ///
 /// Expects that the struct has a wrapping `RefCell` containing a struct
 /// with a `state`, `prev_state`, `native`, `has_pending_dpi_adjustment`,
-/// `border_delta`, `display_state`, and a `done_window_rules` field.
+/// `border_delta`, `display_state`, `done_window_rules`, and an
+/// `aspect_ratio_lock` field.
 #[macro_export]
 macro_rules! impl_window_getters {
   ($struct_name:ident) => {
@@ -217,6 +224,14 @@ macro_rules! impl_window_getters {
       fn set_active_drag(&self, active_drag: Option<ActiveDrag>) {
         self.0.borrow_mut().active_drag = active_drag;
       }
+
+      fn aspect_ratio_lock(&self) -> Option<f32> {
+        self.0.borrow().aspect_ratio_lock
+      }
+
+      fn set_aspect_ratio_lock(&self, aspect_ratio_lock: Option<f32>) {
+        self.0.borrow_mut().aspect_ratio_lock = aspect_ratio_lock;
+      }
     }
   };
 }
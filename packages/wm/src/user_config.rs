@@ -1,9 +1,10 @@
 use std::{collections::HashMap, env, fs, path::PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use wm_common::{
-  InvokeCommand, MatchType, ParsedConfig, WindowMatchConfig,
-  WindowRuleConfig, WindowRuleEvent, WorkspaceConfig,
+  DropRuleConfig, FloatingPlacementConfig, InvokeCommand, MatchType,
+  ParsedConfig, WindowMatchConfig, WindowRuleConfig, WindowRuleEvent,
+  WorkspaceConfig,
 };
 
 use crate::{
@@ -57,6 +58,20 @@ impl UserConfig {
     })
   }
 
+  /// Creates an instance of `UserConfig` from an already parsed config,
+  /// without reading from disk.
+  #[cfg(test)]
+  pub fn from_value(config_value: ParsedConfig) -> Self {
+    let window_rules_by_event = Self::window_rules_by_event(&config_value);
+
+    Self {
+      path: PathBuf::new(),
+      value: config_value,
+      value_str: String::new(),
+      window_rules_by_event,
+    }
+  }
+
   /// Reads and validates the user config from the given path.
   ///
   /// Creates a new config file from sample if it doesn't exist.
@@ -73,10 +88,36 @@ impl UserConfig {
     // TODO: Improve error formatting of serde_yaml errors. Something
     // similar to https://github.com/AlexanderThaller/format_serde_error
     let config_value = serde_yaml::from_str(&config_str)?;
+    Self::validate(&config_value)?;
 
     Ok((config_value, config_str))
   }
 
+  /// Validates config values that can't be checked on deserialization.
+  fn validate(config_value: &ParsedConfig) -> anyhow::Result<()> {
+    let center_fraction =
+      config_value.window_behavior.drop.center_fraction;
+
+    if !(0. ..=1.).contains(&center_fraction) {
+      bail!(
+        "Invalid `window_behavior.drop.center_fraction` value \
+        '{center_fraction}'. Must be between 0 and 1."
+      );
+    }
+
+    let new_split_ratio =
+      config_value.window_behavior.drop.new_split_ratio;
+
+    if !(0. ..1.).contains(&new_split_ratio) || new_split_ratio <= 0. {
+      bail!(
+        "Invalid `window_behavior.drop.new_split_ratio` value \
+        '{new_split_ratio}'. Must be between 0 and 1."
+      );
+    }
+
+    Ok(())
+  }
+
   /// Initializes a new config file from the sample config resource.
   fn create_sample(config_path: &PathBuf) -> Result<()> {
     let parent_dir =
@@ -140,6 +181,8 @@ impl UserConfig {
       ],
       on: vec![WindowRuleEvent::Manage],
       run_once: true,
+      floating_placement: None,
+      preserve_aspect_ratio: false,
     });
 
     // Default ignore rules.
@@ -188,6 +231,8 @@ impl UserConfig {
       ],
       on: vec![WindowRuleEvent::Manage],
       run_once: true,
+      floating_placement: None,
+      preserve_aspect_ratio: false,
     });
 
     window_rules
@@ -240,24 +285,12 @@ impl UserConfig {
         }
 
         // Check if the window matches the rule.
-        rule.match_window.iter().any(|match_config| {
-          let is_process_match = match_config
-            .window_process
-            .as_ref()
-            .is_none_or(|match_type| match_type.is_match(&window_process));
-
-          let is_class_match = match_config
-            .window_class
-            .as_ref()
-            .is_none_or(|match_type| match_type.is_match(&window_class));
-
-          let is_title_match = match_config
-            .window_title
-            .as_ref()
-            .is_none_or(|match_type| match_type.is_match(&window_title));
-
-          is_process_match && is_class_match && is_title_match
-        })
+        Self::is_window_match(
+          &rule.match_window,
+          &window_process,
+          &window_class,
+          &window_title,
+        )
       })
       .cloned()
       .collect::<Vec<_>>();
@@ -265,6 +298,121 @@ impl UserConfig {
     Ok(pending_window_rules)
   }
 
+  /// Gets the drop rule that applies to the window, if any.
+  ///
+  /// Rules are checked in the order they're defined in the config, and
+  /// only the first matching rule is returned.
+  pub fn drop_rule(
+    &self,
+    window: &WindowContainer,
+  ) -> anyhow::Result<Option<&DropRuleConfig>> {
+    let rules = &self.value.window_behavior.drop.rules;
+
+    if rules.is_empty() {
+      return Ok(None);
+    }
+
+    let window_title = window.native().title()?;
+    let window_class = window.native().class_name()?;
+    let window_process = window.native().process_name()?;
+
+    Ok(rules.iter().find(|rule| {
+      Self::is_window_match(
+        &rule.match_window,
+        &window_process,
+        &window_class,
+        &window_title,
+      )
+    }))
+  }
+
+  /// Gets the floating placement of the first window rule that matches
+  /// the window and has one set.
+  pub fn floating_placement_rule(
+    &self,
+    window: &WindowContainer,
+  ) -> anyhow::Result<Option<&FloatingPlacementConfig>> {
+    Ok(
+      self
+        .window_rule_match(window, |rule| {
+          rule.floating_placement.is_some()
+        })?
+        .and_then(|rule| rule.floating_placement.as_ref()),
+    )
+  }
+
+  /// Whether any window rule that matches the window has
+  /// `preserve_aspect_ratio` enabled.
+  pub fn has_aspect_ratio_rule(
+    &self,
+    window: &WindowContainer,
+  ) -> anyhow::Result<bool> {
+    Ok(
+      self
+        .window_rule_match(window, |rule| rule.preserve_aspect_ratio)?
+        .is_some(),
+    )
+  }
+
+  /// Gets the first window rule that satisfies the predicate and matches
+  /// the window.
+  fn window_rule_match(
+    &self,
+    window: &WindowContainer,
+    predicate: impl Fn(&WindowRuleConfig) -> bool,
+  ) -> anyhow::Result<Option<&WindowRuleConfig>> {
+    let mut rules = self
+      .value
+      .window_rules
+      .iter()
+      .filter(|rule| predicate(rule))
+      .peekable();
+
+    if rules.peek().is_none() {
+      return Ok(None);
+    }
+
+    let window_title = window.native().title()?;
+    let window_class = window.native().class_name()?;
+    let window_process = window.native().process_name()?;
+
+    Ok(rules.find(|rule| {
+      Self::is_window_match(
+        &rule.match_window,
+        &window_process,
+        &window_class,
+        &window_title,
+      )
+    }))
+  }
+
+  /// Whether the window properties satisfy any of the match configs.
+  fn is_window_match(
+    match_configs: &[WindowMatchConfig],
+    window_process: &str,
+    window_class: &str,
+    window_title: &str,
+  ) -> bool {
+    match_configs.iter().any(|match_config| {
+      let is_process_match = match_config
+        .window_process
+        .as_ref()
+        .is_none_or(|match_type| match_type.is_match(window_process));
+
+      let is_class_match = match_config
+        .window_class
+        .as_ref()
+        .is_none_or(|match_type| match_type.is_match(window_class));
+
+      let is_title_match = match_config
+        .window_title
+        .as_ref()
+        .is_none_or(|match_type| match_type.is_match(window_title));
+
+      is_process_match && is_class_match && is_title_match
+    })
+  }
+
   pub fn inactive_workspace_configs(
     &self,
     active_workspaces: &[Workspace],
@@ -350,3 +498,38 @@ impl UserConfig {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn validates_center_fraction_range() {
+    let mut config_value = ParsedConfig::default();
+
+    for center_fraction in [0., 0.5, 1.] {
+      config_value.window_behavior.drop.center_fraction = center_fraction;
+      assert!(UserConfig::validate(&config_value).is_ok());
+    }
+
+    for center_fraction in [-0.1, 1.1, f32::NAN] {
+      config_value.window_behavior.drop.center_fraction = center_fraction;
+      assert!(UserConfig::validate(&config_value).is_err());
+    }
+  }
+
+  #[test]
+  fn validates_new_split_ratio_range() {
+    let mut config_value = ParsedConfig::default();
+
+    for new_split_ratio in [0.1, 0.5, 0.9] {
+      config_value.window_behavior.drop.new_split_ratio = new_split_ratio;
+      assert!(UserConfig::validate(&config_value).is_ok());
+    }
+
+    for new_split_ratio in [0., 1., -0.5, 1.5, f32::NAN] {
+      config_value.window_behavior.drop.new_split_ratio = new_split_ratio;
+      assert!(UserConfig::validate(&config_value).is_err());
+    }
+  }
+}
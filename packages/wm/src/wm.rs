@@ -4,33 +4,40 @@ use tracing::warn;
 use uuid::Uuid;
 use wm_common::{
   FloatingStateConfig, FullscreenStateConfig, InvokeCommand, LengthValue,
-  RectDelta, TitleBarVisibility, WindowState, WmEvent,
+  Point, RectDelta, TitleBarVisibility, WindowState, WmEvent,
 };
 use wm_platform::PlatformEvent;
 
 use crate::{
   commands::{
     container::{
-      focus_container_by_id, focus_in_direction, set_tiling_direction,
-      toggle_tiling_direction,
+      balance_container, equalize_axis, focus_container_by_id,
+      focus_floating, focus_in_direction, focus_window_under_cursor,
+      set_tiling_direction, toggle_tiling_direction, transpose_layout,
     },
     general::{
       cycle_focus, disable_binding_mode, enable_binding_mode,
-      platform_sync, reload_config, shell_exec, toggle_pause,
+      platform_sync, redo_layout, reload_config, shell_exec, toggle_pause,
+      undo_layout,
     },
-    monitor::focus_monitor,
+    monitor::{focus_monitor, focus_monitor_by_position},
     window::{
-      ignore_window, move_window_in_direction, move_window_to_workspace,
-      resize_window, set_window_position, set_window_size,
+      cancel_grab, confirm_grab, float_all_of_app, grab_window,
+      ignore_window, move_grab_anchor, move_window_in_direction,
+      move_window_to_monitor_in_direction, move_window_to_workspace,
+      peek_window, rescue_all_floating, resize_window,
+      set_window_position, set_window_size, set_window_sticky,
       update_window_state, WindowPositionTarget,
     },
     workspace::{focus_workspace, move_workspace_in_direction},
   },
   events::{
-    handle_display_settings_changed, handle_mouse_move,
-    handle_window_destroyed, handle_window_focused, handle_window_hidden,
+    handle_display_settings_changed, handle_edge_dwell_elapsed,
+    handle_mouse_move, handle_mouse_wheel, handle_window_destroyed,
+    handle_window_focused, handle_window_hidden,
     handle_window_location_changed, handle_window_minimize_ended,
-    handle_window_minimized, handle_window_moved_or_resized_end,
+    handle_window_minimized, handle_window_moved_or_resized_canceled,
+    handle_window_moved_or_resized_end,
     handle_window_moved_or_resized_start, handle_window_shown,
     handle_window_title_changed,
   },
@@ -43,6 +50,7 @@ use crate::{
 pub struct WindowManager {
   pub event_rx: mpsc::UnboundedReceiver<WmEvent>,
   pub exit_rx: mpsc::UnboundedReceiver<()>,
+  pub edge_dwell_rx: mpsc::UnboundedReceiver<()>,
   pub state: WmState,
 }
 
@@ -50,17 +58,35 @@ impl WindowManager {
   pub fn new(config: &mut UserConfig) -> anyhow::Result<Self> {
     let (event_tx, event_rx) = mpsc::unbounded_channel();
     let (exit_tx, exit_rx) = mpsc::unbounded_channel();
+    let (edge_dwell_tx, edge_dwell_rx) = mpsc::unbounded_channel();
 
-    let mut state = WmState::new(event_tx, exit_tx);
+    let mut state = WmState::new(event_tx, exit_tx, edge_dwell_tx);
     state.populate(config)?;
 
     Ok(Self {
       event_rx,
       exit_rx,
+      edge_dwell_rx,
       state,
     })
   }
 
+  /// Processes a scheduled edge dwell check.
+  pub fn process_edge_dwell(
+    &mut self,
+    config: &mut UserConfig,
+  ) -> anyhow::Result<()> {
+    let state = &mut self.state;
+
+    handle_edge_dwell_elapsed(state, config)?;
+
+    if !state.is_paused && state.pending_sync.has_changes() {
+      platform_sync(state, config)?;
+    }
+
+    Ok(())
+  }
+
   pub fn process_event(
     &mut self,
     event: PlatformEvent,
@@ -81,6 +107,9 @@ impl WindowManager {
       PlatformEvent::MouseMove(event) => {
         handle_mouse_move(&event, state, config)
       }
+      PlatformEvent::MouseWheel(event) => {
+        handle_mouse_wheel(&event, state, config)
+      }
       PlatformEvent::WindowDestroyed(window) => {
         handle_window_destroyed(&window, state)
       }
@@ -99,6 +128,9 @@ impl WindowManager {
       PlatformEvent::WindowMinimizeEnded(window) => {
         handle_window_minimize_ended(&window, state, config)
       }
+      PlatformEvent::WindowMovedOrResizedCanceled(window) => {
+        handle_window_moved_or_resized_canceled(&window, state, config)
+      }
       PlatformEvent::WindowMovedOrResizedEnd(window) => {
         handle_window_moved_or_resized_end(&window, state, config)
       }
@@ -234,9 +266,41 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::Balance { workspace } => {
+        let target = if *workspace {
+          subject_container.workspace().map(Into::into)
+        } else {
+          subject_container.direction_container()
+        };
+
+        if let Some(target) = target {
+          balance_container(&target, state);
+        }
+
+        Ok(())
+      }
+      InvokeCommand::EqualizeAxis { tiling_direction } => {
+        equalize_axis(&subject_container, tiling_direction, state)
+      }
+      InvokeCommand::FloatAllOfApp { process } => {
+        let process_name = match process {
+          Some(process) => process.clone(),
+          None => match subject_container.as_window_container() {
+            Ok(window) => window.native().process_name()?,
+            _ => return Ok(()),
+          },
+        };
+
+        float_all_of_app(&process_name, state, config)
+      }
       InvokeCommand::Focus(args) => {
         if let Some(direction) = &args.direction {
-          focus_in_direction(&subject_container, direction, state)?;
+          focus_in_direction(
+            &subject_container,
+            direction,
+            state,
+            config,
+          )?;
         }
 
         if let Some(direction) = &args.workspace_in_direction {
@@ -283,6 +347,19 @@ impl WindowManager {
           focus_workspace(WorkspaceTarget::Recent, state, config)?;
         }
 
+        if args.under_cursor {
+          focus_window_under_cursor(state)?;
+        }
+
+        if args.next_floating || args.prev_floating {
+          focus_floating(
+            &subject_container,
+            args.prev_floating,
+            state,
+            config,
+          )?;
+        }
+
         if args.next_active_workspace_on_monitor {
           focus_workspace(
             WorkspaceTarget::NextActiveInMonitor,
@@ -301,6 +378,28 @@ impl WindowManager {
 
         Ok(())
       }
+      InvokeCommand::FocusMonitorByPosition { x, y } => {
+        focus_monitor_by_position(&Point { x: *x, y: *y }, state, config)
+      }
+      InvokeCommand::Grab {
+        direction,
+        confirm,
+        cancel,
+      } => {
+        if *cancel {
+          cancel_grab(state);
+          Ok(())
+        } else if *confirm {
+          confirm_grab(state, config)
+        } else if let Some(direction) = direction {
+          move_grab_anchor(direction, state, config)
+        } else {
+          match subject_container.as_tiling_window() {
+            Some(window) => grab_window(window, state),
+            _ => Ok(()),
+          }
+        }
+      }
       InvokeCommand::Ignore => {
         match subject_container.as_window_container() {
           Ok(window) => ignore_window(window, state),
@@ -319,6 +418,12 @@ impl WindowManager {
               )?;
             }
 
+            if let Some(direction) = &args.monitor_in_direction {
+              move_window_to_monitor_in_direction(
+                &window, direction, state,
+              )?;
+            }
+
             if let Some(direction) = &args.workspace_in_direction {
               move_window_to_workspace(
                 window.clone(),
@@ -411,6 +516,12 @@ impl WindowManager {
 
         move_workspace_in_direction(&workspace, direction, state, config)
       }
+      InvokeCommand::PeekFocused { scale } => {
+        match subject_container.as_window_container() {
+          Ok(window) => peek_window(&window, *scale, state, config),
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::Position(args) => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -431,6 +542,7 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::RescueAllFloating => rescue_all_floating(state),
       InvokeCommand::Resize(args) => {
         match subject_container.as_window_container() {
           Ok(window) => resize_window(
@@ -438,7 +550,9 @@ impl WindowManager {
             args.width.clone(),
             args.height.clone(),
             state,
-          ),
+            config,
+          )
+          .map(|_| ()),
           _ => Ok(()),
         }
       }
@@ -551,6 +665,15 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::SetSticky { sticky } => {
+        match subject_container.as_window_container() {
+          Ok(window) => {
+            set_window_sticky(&window, *sticky);
+            Ok(())
+          }
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::SetTitleBarVisibility { visibility } => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -687,6 +810,9 @@ impl WindowManager {
       InvokeCommand::ToggleTilingDirection => {
         toggle_tiling_direction(subject_container, state, config)
       }
+      InvokeCommand::TransposeLayout => {
+        transpose_layout(&subject_container, state)
+      }
       InvokeCommand::SetTilingDirection { tiling_direction } => {
         set_tiling_direction(
           subject_container,
@@ -715,6 +841,8 @@ impl WindowManager {
       InvokeCommand::WmEnableBindingMode { name } => {
         enable_binding_mode(name, state, config)
       }
+      InvokeCommand::Undo => undo_layout(state, config),
+      InvokeCommand::Redo => redo_layout(state, config),
       InvokeCommand::WmExit => state.emit_exit(),
       InvokeCommand::WmRedraw => {
         state
@@ -1,7 +1,13 @@
-use std::time::Instant;
+use std::{
+  collections::{HashMap, HashSet},
+  time::{Duration, Instant},
+};
 
 use anyhow::Context;
-use tokio::sync::mpsc::{self};
+use tokio::{
+  sync::mpsc::{self},
+  task,
+};
 use tracing::warn;
 use uuid::Uuid;
 use wm_common::{
@@ -15,8 +21,8 @@ use crate::{
     monitor::add_monitor, window::manage_window,
   },
   models::{
-    Container, Monitor, RootContainer, WindowContainer, Workspace,
-    WorkspaceTarget,
+    Container, KeyboardGrab, LayoutHistory, Monitor, RootContainer,
+    WindowContainer, Workspace, WorkspaceTarget,
   },
   pending_sync::PendingSync,
   traits::{CommonGetters, PositionGetters, WindowGetters},
@@ -48,6 +54,27 @@ pub struct WmState {
   /// Used to decide whether to override incoming focus events.
   pub unmanaged_or_minimized_timestamp: Option<Instant>,
 
+  /// Window that is temporarily enlarged via the `peek-focused` command.
+  ///
+  /// The window's position in the tree is left unchanged while peeked.
+  pub peeked_window: Option<WindowContainer>,
+
+  /// Window that is grabbed via the `grab` command, along with the point
+  /// it'll be dropped at.
+  pub keyboard_grab: Option<KeyboardGrab>,
+
+  /// When a move/resize end event was last handled for each window.
+  ///
+  /// Used to ignore repeated end events for the same drag.
+  pub drag_end_timestamps: HashMap<Uuid, Instant>,
+
+  /// Windows that have had a floating placement from a window rule
+  /// applied.
+  pub floating_placed_windows: HashSet<Uuid>,
+
+  /// Layout changes that can be undone via the `undo` command.
+  pub layout_history: LayoutHistory,
+
   /// Configs of currently enabled binding modes.
   pub binding_modes: Vec<BindingModeConfig>,
 
@@ -69,12 +96,17 @@ pub struct WmState {
 
   /// Sender for gracefully shutting down the WM.
   exit_tx: mpsc::UnboundedSender<()>,
+
+  /// Sender for notifying that a dragged window may have been held at a
+  /// screen edge for long enough.
+  edge_dwell_tx: mpsc::UnboundedSender<()>,
 }
 
 impl WmState {
   pub fn new(
     event_tx: mpsc::UnboundedSender<WmEvent>,
     exit_tx: mpsc::UnboundedSender<()>,
+    edge_dwell_tx: mpsc::UnboundedSender<()>,
   ) -> Self {
     Self {
       root_container: RootContainer::new(),
@@ -82,6 +114,11 @@ impl WmState {
       prev_effects_window: None,
       recent_workspace_name: None,
       unmanaged_or_minimized_timestamp: None,
+      peeked_window: None,
+      keyboard_grab: None,
+      drag_end_timestamps: HashMap::new(),
+      floating_placed_windows: HashSet::new(),
+      layout_history: LayoutHistory::default(),
       binding_modes: Vec::new(),
       ignored_windows: Vec::new(),
       is_paused: false,
@@ -89,9 +126,17 @@ impl WmState {
       has_initialized: false,
       event_tx,
       exit_tx,
+      edge_dwell_tx,
     }
   }
 
+  /// Marks the state as initialized without populating it, so that events
+  /// get emitted.
+  #[cfg(test)]
+  pub fn set_initialized(&mut self) {
+    self.has_initialized = true;
+  }
+
   /// Populates the initial WM state by creating containers for all
   /// existing windows and monitors.
   pub fn populate(
@@ -203,7 +248,9 @@ impl WmState {
 
   /// Gets the closest monitor in a given direction.
   ///
-  /// Uses i3wm's algorithm for finding best guess.
+  /// Monitors are scored by the gap between their edges and how much they
+  /// overlap on the perpendicular axis, so that offset or stacked
+  /// monitors resolve to the best-overlapping neighbor.
   pub fn monitor_in_direction(
     &self,
     origin_monitor: &Monitor,
@@ -211,39 +258,15 @@ impl WmState {
   ) -> anyhow::Result<Option<Monitor>> {
     let origin_rect = origin_monitor.native().rect()?.clone();
 
-    // Create a tuple of monitors and their rect.
-    let monitors_with_rect = self
-      .monitors()
-      .into_iter()
-      .map(|monitor| {
-        let rect = monitor.native().rect()?.clone();
-        anyhow::Ok((monitor, rect))
-      })
+    let monitors = self.monitors();
+    let monitor_rects = monitors
+      .iter()
+      .map(|monitor| anyhow::Ok(monitor.native().rect()?.clone()))
       .try_collect::<Vec<_>>()?;
 
-    let closest_monitor = monitors_with_rect
-      .into_iter()
-      .filter(|(_, rect)| match direction {
-        Direction::Right => {
-          rect.x() > origin_rect.x() && rect.has_overlap_y(&origin_rect)
-        }
-        Direction::Left => {
-          rect.x() < origin_rect.x() && rect.has_overlap_y(&origin_rect)
-        }
-        Direction::Down => {
-          rect.y() > origin_rect.y() && rect.has_overlap_x(&origin_rect)
-        }
-        Direction::Up => {
-          rect.y() < origin_rect.y() && rect.has_overlap_x(&origin_rect)
-        }
-      })
-      .min_by(|(_, rect_a), (_, rect_b)| match direction {
-        Direction::Right => rect_a.x().cmp(&rect_b.x()),
-        Direction::Left => rect_b.x().cmp(&rect_a.x()),
-        Direction::Down => rect_a.y().cmp(&rect_b.y()),
-        Direction::Up => rect_b.y().cmp(&rect_a.y()),
-      })
-      .map(|(monitor, _)| monitor);
+    let closest_monitor = origin_rect
+      .neighbor_in_direction(&monitor_rects, direction)
+      .and_then(|index| monitors.get(index).cloned());
 
     Ok(closest_monitor)
   }
@@ -456,8 +479,7 @@ impl WmState {
   pub fn windows_to_redraw(&self) -> Vec<WindowContainer> {
     self
       .pending_sync
-      .containers_to_redraw()
-      .values()
+      .coalesced_containers_to_redraw()
       .flat_map(CommonGetters::self_and_descendants)
       .filter(|container| !container.is_detached())
       .filter_map(|container| container.try_into().ok())
@@ -486,6 +508,18 @@ impl WmState {
     }
   }
 
+  /// Schedules an edge dwell check after the given duration. This
+  /// completes the dwell even if the cursor is held still at the edge,
+  /// since no mouse move events are received then.
+  pub fn schedule_edge_dwell_check(&self, duration: Duration) {
+    let edge_dwell_tx = self.edge_dwell_tx.clone();
+
+    task::spawn(async move {
+      tokio::time::sleep(duration).await;
+      _ = edge_dwell_tx.send(());
+    });
+  }
+
   /// Starts graceful shutdown via an MSPC channel.
   pub fn emit_exit(&self) -> anyhow::Result<()> {
     self.exit_tx.send(())?;
@@ -565,21 +599,141 @@ impl WmState {
       .collect()
   }
 
+  /// Returns the top-most window at the given point.
+  ///
+  /// Overlapping windows (e.g. a floating window over a tiling window)
+  /// are resolved by their stacking order as reported by the OS. If the
+  /// OS doesn't report a managed window at the point, the most recently
+  /// focused window is used instead.
+  pub fn window_at_point(&self, point: &Point) -> Option<WindowContainer> {
+    let workspace = self.workspace_at_point(point)?;
+
+    let windows = self
+      .containers_at_point(&workspace.clone().into(), point)
+      .into_iter()
+      .filter_map(|container| container.as_window_container().ok())
+      .filter(|window| window.state() != WindowState::Minimized)
+      .collect::<Vec<_>>();
+
+    let native_window = Platform::window_from_point(point)
+      .and_then(|window| Platform::root_ancestor(&window))
+      .ok();
+
+    native_window
+      .and_then(|native_window| {
+        windows
+          .iter()
+          .find(|window| *window.native() == native_window)
+          .cloned()
+      })
+      .or_else(|| {
+        workspace
+          .descendant_focus_order()
+          .filter_map(|descendant| descendant.as_window_container().ok())
+          .find(|descendant| {
+            windows.iter().any(|window| window.id() == descendant.id())
+          })
+      })
+  }
+
   /// Returns the monitor that contains the given point.
+  ///
+  /// If monitors overlap (e.g. mirrored displays), the primary monitor is
+  /// preferred, then the monitor with the smallest area. Remaining ties
+  /// are broken by the monitor's device path and then its handle, so that
+  /// the same monitor is picked across restarts.
   pub fn monitor_at_point(&self, point: &Point) -> Option<Monitor> {
-    self
+    let mut candidates = self
       .monitors()
-      .iter()
-      .find(|monitor| {
-        monitor
-          .to_rect()
-          .map(|rect| rect.contains_point(point))
-          .unwrap_or(false)
+      .into_iter()
+      .filter_map(|monitor| {
+        let rect = monitor.to_rect().ok()?;
+
+        rect.contains_point(point).then(|| {
+          let area = i64::from(rect.width()) * i64::from(rect.height());
+          (area, monitor)
+        })
       })
-      .cloned()
+      .collect::<Vec<_>>();
+
+    // Native monitor info is only needed to pick between overlapping
+    // monitors.
+    if candidates.len() <= 1 {
+      return candidates.pop().map(|(_, monitor)| monitor);
+    }
+
+    candidates
+      .into_iter()
+      .min_by_key(|(area, monitor)| {
+        overlapping_monitor_order(&monitor.native(), *area)
+      })
+      .map(|(_, monitor)| monitor)
+  }
+
+  /// Returns the displayed workspace of the monitor that contains the
+  /// given point.
+  pub fn workspace_at_point(&self, point: &Point) -> Option<Workspace> {
+    self
+      .monitor_at_point(point)
+      .and_then(|monitor| monitor.displayed_workspace())
+  }
+
+  /// Returns the monitor that contains the given point, or otherwise the
+  /// monitor nearest to it (e.g. for points in the dead corners of an
+  /// L-shaped monitor arrangement).
+  pub fn nearest_monitor_to_point(
+    &self,
+    point: &Point,
+  ) -> Option<Monitor> {
+    self.monitor_at_point(point).or_else(|| {
+      self
+        .monitors()
+        .into_iter()
+        .filter_map(|monitor| {
+          let distance = monitor.to_rect().ok()?.distance_to_point(point);
+          Some((monitor, distance))
+        })
+        .min_by(|(_, distance_a), (_, distance_b)| {
+          distance_a.total_cmp(distance_b)
+        })
+        .map(|(monitor, _)| monitor)
+    })
+  }
+
+  /// Whether the given point is over an empty area of a monitor (e.g. a
+  /// gap between windows or an empty workspace).
+  ///
+  /// This checks the root native window hit-tested at the point rather
+  /// than the managed windows, so that unmanaged windows (e.g. the taskbar
+  /// or ignored windows) aren't treated as gaps.
+  ///
+  /// Returns `false` if the point is outside of all monitors.
+  pub fn is_point_over_gap(
+    &self,
+    point: &Point,
+    hit_window: Option<&NativeWindow>,
+  ) -> bool {
+    self.workspace_at_point(point).is_some()
+      && hit_window.is_some_and(Platform::is_desktop_background)
   }
 }
 
+/// Sort key for picking between overlapping monitors, where the lowest
+/// key is preferred.
+///
+/// See `WmState::monitor_at_point` for the ordering.
+fn overlapping_monitor_order(
+  native_monitor: &NativeMonitor,
+  area: i64,
+) -> (bool, i64, Option<String>, isize) {
+  (
+    !native_monitor.is_primary().unwrap_or(false),
+    area,
+    native_monitor.device_path().ok().flatten().cloned(),
+    native_monitor.handle,
+  )
+}
+
 impl Drop for WmState {
   fn drop(&mut self) {
     let managed_windows = self
@@ -593,3 +747,96 @@ impl Drop for WmState {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::test_utils::{attach, monitor, wm_state, workspace};
+
+  #[test]
+  fn orders_overlapping_monitors() {
+    let primary =
+      NativeMonitor::from_rect(2, Rect::from_xy(0, 0, 1920, 1080), true);
+    let secondary =
+      NativeMonitor::from_rect(1, Rect::from_xy(0, 0, 1280, 720), false);
+
+    // Primary wins even if it's larger.
+    assert!(
+      overlapping_monitor_order(&primary, 1920 * 1080)
+        < overlapping_monitor_order(&secondary, 1280 * 720)
+    );
+
+    // Otherwise, the smaller monitor wins, and then the lower handle.
+    let tertiary =
+      NativeMonitor::from_rect(0, Rect::from_xy(0, 0, 1280, 720), false);
+
+    assert!(
+      overlapping_monitor_order(&secondary, 1280 * 720)
+        < overlapping_monitor_order(&tertiary, 1920 * 1080)
+    );
+    assert!(
+      overlapping_monitor_order(&tertiary, 1280 * 720)
+        < overlapping_monitor_order(&secondary, 1280 * 720)
+    );
+  }
+
+  #[test]
+  fn prefers_primary_of_overlapping_monitors() {
+    let primary_workspace = workspace(TilingDirection::Horizontal);
+    let state = wm_state(&primary_workspace);
+
+    let primary_monitor =
+      primary_workspace.monitor().expect("No primary monitor.");
+
+    // Smaller monitor mirroring the top-left of the primary monitor.
+    let mirrored_monitor =
+      monitor(1, Rect::from_xy(0, 0, 1280, 720), false);
+    attach(mirrored_monitor.clone(), state.root_container.clone());
+    attach(
+      workspace(TilingDirection::Horizontal),
+      mirrored_monitor.clone(),
+    );
+
+    assert_eq!(
+      state
+        .monitor_at_point(&Point { x: 100, y: 100 })
+        .map(|monitor| monitor.id()),
+      Some(primary_monitor.id())
+    );
+
+    // Only the primary monitor contains the point.
+    assert_eq!(
+      state
+        .monitor_at_point(&Point { x: 1500, y: 900 })
+        .map(|monitor| monitor.id()),
+      Some(primary_monitor.id())
+    );
+  }
+
+  #[test]
+  fn returns_no_workspace_for_monitor_without_workspaces() {
+    let primary_workspace = workspace(TilingDirection::Horizontal);
+    let state = wm_state(&primary_workspace);
+
+    let empty_monitor =
+      monitor(1, Rect::from_xy(1920, 0, 1920, 1080), false);
+    attach(empty_monitor.clone(), state.root_container.clone());
+
+    let point = Point { x: 2500, y: 500 };
+
+    assert_eq!(
+      state.monitor_at_point(&point).map(|monitor| monitor.id()),
+      Some(empty_monitor.id())
+    );
+    assert!(state.workspace_at_point(&point).is_none());
+
+    assert_eq!(
+      state
+        .workspace_at_point(&Point { x: 500, y: 500 })
+        .map(|workspace| workspace.id()),
+      Some(primary_workspace.id())
+    );
+  }
+}